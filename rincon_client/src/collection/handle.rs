@@ -0,0 +1,93 @@
+//! A fluent, object-oriented handle for working with a single collection.
+
+use rincon_core::api::connector::{Connector, Execute, FutureResult};
+use super::methods::*;
+use super::types::*;
+
+/// A handle to a collection that binds its name together with a connector,
+/// offering async convenience methods that internally build and dispatch
+/// the low-level `Method`/`Prepare` structs defined in this module.
+///
+/// This does not replace the individual method structs, which remain
+/// available for advanced use cases such as pipelining several methods
+/// through a custom connector.
+#[derive(Debug, Clone)]
+pub struct CollectionHandle<'a, C: 'a> {
+    name: String,
+    connector: &'a C,
+}
+
+impl<'a, C: 'a + Connector> CollectionHandle<'a, C> {
+    /// Constructs a new `CollectionHandle` for the collection with the
+    /// given name, bound to the given connector.
+    pub fn new<N>(name: N, connector: &'a C) -> Self
+        where N: Into<String>
+    {
+        CollectionHandle {
+            name: name.into(),
+            connector,
+        }
+    }
+
+    /// Returns the name of the collection this handle refers to.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Fetches the properties of this collection.
+    pub fn properties(&self) -> FutureResult<GetCollectionProperties> {
+        self.connector.execute(GetCollectionProperties::with_name(&self.name))
+    }
+
+    /// Fetches the number of documents in this collection.
+    pub fn document_count(&self) -> FutureResult<GetCollectionDocumentCount> {
+        self.connector.execute(GetCollectionDocumentCount::with_name(&self.name))
+    }
+
+    /// Fetches the revision of this collection.
+    pub fn revision(&self) -> FutureResult<GetCollectionRevision> {
+        self.connector.execute(GetCollectionRevision::with_name(&self.name))
+    }
+
+    /// Fetches the checksum of this collection.
+    pub fn checksum(&self) -> FutureResult<GetCollectionChecksum> {
+        self.connector.execute(GetCollectionChecksum::with_name(&self.name))
+    }
+
+    /// Fetches the figures (statistics) of this collection.
+    pub fn figures(&self) -> FutureResult<GetCollectionFigures> {
+        self.connector.execute(GetCollectionFigures::with_name(&self.name))
+    }
+
+    /// Changes the properties of this collection.
+    pub fn change_properties(&self, updates: CollectionPropertiesUpdate) -> FutureResult<ChangeCollectionProperties> {
+        self.connector.execute(ChangeCollectionProperties::new(self.name.clone(), updates))
+    }
+
+    /// Renames this collection to the given new name.
+    ///
+    /// **Note:** this method is not available in a cluster, which is why it
+    /// is only compiled in when the `cluster` feature is disabled.
+    #[cfg(not(feature = "cluster"))]
+    pub fn rename_to<N>(&self, new_name: N) -> FutureResult<RenameCollection>
+        where N: Into<String>
+    {
+        self.connector.execute(RenameCollection::with_name(&self.name).to_name(new_name))
+    }
+
+    /// Removes all documents from this collection, leaving the collection
+    /// itself and its indexes intact.
+    pub fn truncate(&self) -> FutureResult<TruncateCollection> {
+        self.connector.execute(TruncateCollection::with_name(&self.name))
+    }
+
+    /// Loads this collection into memory.
+    pub fn load(&self) -> FutureResult<LoadCollection> {
+        self.connector.execute(LoadCollection::with_name(&self.name))
+    }
+
+    /// Unloads this collection from memory.
+    pub fn unload(&self) -> FutureResult<UnloadCollection> {
+        self.connector.execute(UnloadCollection::with_name(&self.name))
+    }
+}
@@ -4,7 +4,8 @@ use rincon_core::api::method::{Method, Operation, Parameters, Prepare, RpcReturn
 use rincon_core::arango::protocol::{FIELD_CODE, FIELD_ID, FIELD_RESULT,
     PARAM_EXCLUDE_SYSTEM, PATH_API_COLLECTION, PATH_PROPERTIES, PATH_RENAME,
     PATH_REVISION, PARAM_WITH_REVISIONS, PARAM_WITH_DATA, PATH_CHECKSUM,
-    PATH_DOCUMENT_COUNT};
+    PATH_DOCUMENT_COUNT, PATH_TRUNCATE, PATH_FIGURES, PATH_LOAD, PATH_UNLOAD,
+    PATH_LOAD_INDEXES_INTO_MEMORY, PATH_RECALCULATE_COUNT};
 #[cfg(feature = "cluster")]
 use rincon_core::arango::protocol::PARAM_WAIT_FOR_SYNC_REPLICATION;
 use super::types::*;
@@ -305,6 +306,101 @@ impl Prepare for DropCollection {
     }
 }
 
+/// Removes all documents from the collection identified by the given name,
+/// but leaves the collection itself and its indexes intact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TruncateCollection {
+    name: String,
+    #[cfg(feature = "cluster")]
+    wait_for_sync_replication: bool,
+}
+
+impl TruncateCollection {
+    /// Constructs a new instance of the `TruncateCollection` method that is
+    /// going to truncate the collection with the given name.
+    pub fn new(name: String) -> Self {
+        TruncateCollection {
+            name,
+            #[cfg(feature = "cluster")]
+            wait_for_sync_replication: true,
+        }
+    }
+
+    /// Constructs a new instance of the `TruncateCollection` method that is
+    /// going to truncate the collection identified by the given name.
+    pub fn with_name<N>(name: N) -> Self
+        where N: Into<String>
+    {
+        TruncateCollection {
+            name: name.into(),
+            #[cfg(feature = "cluster")]
+            wait_for_sync_replication: true,
+        }
+    }
+
+    #[cfg(feature = "cluster")]
+    /// Set whether the server shall wait until the truncation has been
+    /// applied on all replications before it returns the response.
+    pub fn set_wait_for_sync_replication(&mut self, wait_for_sync_replication: bool) {
+        self.wait_for_sync_replication = wait_for_sync_replication;
+    }
+
+    /// Returns the name of the collection to be truncated.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[cfg(feature = "cluster")]
+    /// Returns whether the request will wait until the truncation has been
+    /// applied on all replications.
+    pub fn is_wait_for_sync_replication(&self) -> bool {
+        self.wait_for_sync_replication
+    }
+}
+
+impl Method for TruncateCollection {
+    type Result = Collection;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for TruncateCollection {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_COLLECTION)
+            + "/" + &self.name + PATH_TRUNCATE
+    }
+
+    #[cfg(not(feature = "cluster"))]
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    #[cfg(feature = "cluster")]
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(1);
+        if !self.wait_for_sync_replication {
+            params.insert(PARAM_WAIT_FOR_SYNC_REPLICATION, 0);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
 /// Fetch information about the collection identified by the given name.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetCollection {
@@ -457,6 +553,142 @@ impl Prepare for GetCollectionChecksum {
     }
 }
 
+/// Loads all indexes of the collection identified by the given name into
+/// memory.
+///
+/// This can be used to pre-warm a collection's indexes before a
+/// latency-sensitive workload starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadIndexesIntoMemory {
+    name: String,
+}
+
+impl LoadIndexesIntoMemory {
+    /// Constructs a new instance of the `LoadIndexesIntoMemory` method.
+    pub fn new(name: String) -> Self {
+        LoadIndexesIntoMemory {
+            name,
+        }
+    }
+
+    /// Constructs a new instance of the `LoadIndexesIntoMemory` method to
+    /// load the indexes of the collection with the given name into memory.
+    pub fn with_name<N>(name: N) -> Self
+        where N: Into<String>
+    {
+        LoadIndexesIntoMemory {
+            name: name.into(),
+        }
+    }
+
+    /// Returns the name of the collection for which the indexes shall be
+    /// loaded into memory.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Method for LoadIndexesIntoMemory {
+    type Result = bool;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: Some(FIELD_RESULT),
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for LoadIndexesIntoMemory {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_COLLECTION)
+            + "/" + &self.name + PATH_LOAD_INDEXES_INTO_MEMORY
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
+/// Recalculates the document count of the collection identified by the
+/// given name.
+///
+/// This repairs a document counter that has drifted out of sync, e.g. after
+/// an unclean shutdown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecalculateCount {
+    name: String,
+}
+
+impl RecalculateCount {
+    /// Constructs a new instance of the `RecalculateCount` method.
+    pub fn new(name: String) -> Self {
+        RecalculateCount {
+            name,
+        }
+    }
+
+    /// Constructs a new instance of the `RecalculateCount` method to
+    /// recalculate the document count of the collection with the given name.
+    pub fn with_name<N>(name: N) -> Self
+        where N: Into<String>
+    {
+        RecalculateCount {
+            name: name.into(),
+        }
+    }
+
+    /// Returns the name of the collection for which the document count
+    /// shall be recalculated.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Method for RecalculateCount {
+    type Result = RecalculatedCount;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for RecalculateCount {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_COLLECTION)
+            + "/" + &self.name + PATH_RECALCULATE_COUNT
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
 /// Fetch the number of documents in a collection identified by the given name.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetCollectionDocumentCount {
@@ -521,6 +753,73 @@ impl Prepare for GetCollectionDocumentCount {
     }
 }
 
+/// Fetch the statistics of the collection identified by the given name.
+///
+/// **Note**: Retrieving the figures of a collection forces the collection
+/// to be loaded into memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetCollectionFigures {
+    name: String,
+}
+
+impl GetCollectionFigures {
+    /// Constructs a new instance of the `GetCollectionFigures` method.
+    pub fn new(name: String) -> Self {
+        GetCollectionFigures {
+            name,
+        }
+    }
+
+    /// Constructs a new instance of the `GetCollectionFigures` method to get
+    /// the statistics of the collection with the given name.
+    pub fn with_name<N>(name: N) -> Self
+        where N: Into<String>
+    {
+        GetCollectionFigures {
+            name: name.into(),
+        }
+    }
+
+    /// Returns the name of the collection for which the figures shall
+    /// be fetched.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Method for GetCollectionFigures {
+    type Result = CollectionFigures;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for GetCollectionFigures {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Read
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_COLLECTION)
+            + "/" + &self.name + PATH_FIGURES
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
 /// Fetch the revision of the collection identified by the given name.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GetCollectionRevision {
@@ -724,15 +1023,170 @@ impl Prepare for ChangeCollectionProperties {
     }
 }
 
+/// Loads a collection into memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadCollection {
+    name: String,
+    options: LoadCollectionOptions,
+}
+
+impl LoadCollection {
+    /// Constructs a new instance of the `LoadCollection` method that is
+    /// going to load the collection with the given name into memory.
+    ///
+    /// The document count is computed by default. Use `set_count` to skip
+    /// this potentially expensive computation.
+    pub fn new(name: String) -> Self {
+        LoadCollection {
+            name,
+            options: LoadCollectionOptions::new(true),
+        }
+    }
+
+    /// Constructs a new instance of the `LoadCollection` method to load
+    /// the collection identified by the given name into memory.
+    pub fn with_name<N>(name: N) -> Self
+        where N: Into<String>
+    {
+        LoadCollection {
+            name: name.into(),
+            options: LoadCollectionOptions::new(true),
+        }
+    }
+
+    /// Set whether the number of documents in the collection shall be
+    /// determined while loading it into memory.
+    ///
+    /// Counting the documents can be an expensive operation for large
+    /// collections, so this can be disabled to speed up the load.
+    pub fn set_count(&mut self, count: bool) {
+        self.options = LoadCollectionOptions::new(count);
+    }
+
+    /// Returns the name of the collection to be loaded.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether the number of documents in the collection will be
+    /// determined while loading it into memory.
+    pub fn is_count(&self) -> bool {
+        self.options.is_count()
+    }
+}
+
+impl Method for LoadCollection {
+    type Result = Collection;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for LoadCollection {
+    type Content = LoadCollectionOptions;
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_COLLECTION)
+            + "/" + &self.name + PATH_LOAD
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.options)
+    }
+}
+
+/// Unloads a collection from memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnloadCollection {
+    name: String,
+}
+
+impl UnloadCollection {
+    /// Constructs a new instance of the `UnloadCollection` method that is
+    /// going to unload the collection with the given name from memory.
+    pub fn new(name: String) -> Self {
+        UnloadCollection {
+            name,
+        }
+    }
+
+    /// Constructs a new instance of the `UnloadCollection` method to unload
+    /// the collection identified by the given name from memory.
+    pub fn with_name<N>(name: N) -> Self
+        where N: Into<String>
+    {
+        UnloadCollection {
+            name: name.into(),
+        }
+    }
+
+    /// Returns the name of the collection to be unloaded.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Method for UnloadCollection {
+    type Result = Collection;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for UnloadCollection {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_COLLECTION)
+            + "/" + &self.name + PATH_UNLOAD
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
 /// Renames a collection.
 ///
-/// **Note:** this method is not available in a cluster.
+/// **Note:** this method is not available in a cluster. This struct and its
+/// builder are therefore only compiled in when the `cluster` feature is
+/// disabled, so that attempting to rename a collection in a cluster
+/// deployment is a compile error rather than a confusing server-side
+/// rejection.
+#[cfg(not(feature = "cluster"))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct RenameCollection {
     name: String,
     rename_to: RenameTo,
 }
 
+#[cfg(not(feature = "cluster"))]
 impl RenameCollection {
     /// Constructs a new instance of the `RenameCollection` method with all
     /// parameters specified.
@@ -754,6 +1208,7 @@ impl RenameCollection {
     }
 }
 
+#[cfg(not(feature = "cluster"))]
 impl Method for RenameCollection {
     type Result = Collection;
     const RETURN_TYPE: RpcReturnType = RpcReturnType {
@@ -762,6 +1217,7 @@ impl Method for RenameCollection {
     };
 }
 
+#[cfg(not(feature = "cluster"))]
 impl Prepare for RenameCollection {
     type Content = RenameTo;
 
@@ -790,11 +1246,13 @@ impl Prepare for RenameCollection {
 
 /// A struct that helps to provide an efficient fluent API to build a new
 /// instance of the `RenameCollection` method.
+#[cfg(not(feature = "cluster"))]
 #[derive(Debug)]
 pub struct RenameCollectionBuilder {
     collection_name: String,
 }
 
+#[cfg(not(feature = "cluster"))]
 impl RenameCollectionBuilder {
     //noinspection RsSelfConvention
     #[cfg_attr(feature = "cargo-clippy", allow(wrong_self_convention))]
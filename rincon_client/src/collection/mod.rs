@@ -0,0 +1,5 @@
+//! Types and methods for managing ArangoDB collections.
+
+pub mod handle;
+pub mod methods;
+pub mod types;
@@ -0,0 +1,174 @@
+
+/// Holds the recalculated document count of a collection as returned by
+/// the `RecalculateCount` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecalculatedCount {
+    count: u64,
+}
+
+impl RecalculatedCount {
+    /// Returns the recalculated number of documents in the collection.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// The content sent to the server for the `LoadCollection` method to
+/// control whether the potentially expensive document count is computed
+/// while loading the collection.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LoadCollectionOptions {
+    count: bool,
+}
+
+impl LoadCollectionOptions {
+    /// Constructs a new instance of `LoadCollectionOptions` with the given
+    /// `count` setting.
+    pub fn new(count: bool) -> Self {
+        LoadCollectionOptions {
+            count,
+        }
+    }
+
+    /// Returns whether the document count is going to be computed while
+    /// loading the collection.
+    pub fn is_count(&self) -> bool {
+        self.count
+    }
+}
+
+/// Holds the figures (statistics) of a collection as returned by the
+/// `GetCollectionFigures` method.
+///
+/// **Note**: Retrieving the figures of a collection forces the collection
+/// to be loaded into memory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionFigures {
+    #[serde(rename = "figures")]
+    figures: Figures,
+}
+
+impl CollectionFigures {
+    /// Returns the number of documents currently alive in the collection.
+    pub fn alive_count(&self) -> u64 {
+        self.figures.alive.count
+    }
+
+    /// Returns the total size in bytes used by all living documents.
+    pub fn alive_size(&self) -> u64 {
+        self.figures.alive.size
+    }
+
+    /// Returns the number of documents currently dead in the collection.
+    pub fn dead_count(&self) -> u64 {
+        self.figures.dead.count
+    }
+
+    /// Returns the total size in bytes used by all dead documents.
+    pub fn dead_size(&self) -> u64 {
+        self.figures.dead.size
+    }
+
+    /// Returns the number of deletion markers currently dead in the
+    /// collection.
+    pub fn dead_deletion(&self) -> u64 {
+        self.figures.dead.deletion
+    }
+
+    /// Returns the number of active datafiles.
+    pub fn datafiles_count(&self) -> u64 {
+        self.figures.datafiles.count
+    }
+
+    /// Returns the total filesize of the active datafiles.
+    pub fn datafiles_file_size(&self) -> u64 {
+        self.figures.datafiles.file_size
+    }
+
+    /// Returns the number of journal files.
+    pub fn journals_count(&self) -> u64 {
+        self.figures.journals.count
+    }
+
+    /// Returns the total filesize of the journal files.
+    pub fn journals_file_size(&self) -> u64 {
+        self.figures.journals.file_size
+    }
+
+    /// Returns the number of compactor files.
+    pub fn compactors_count(&self) -> u64 {
+        self.figures.compactors.count
+    }
+
+    /// Returns the total filesize of the compactor files.
+    pub fn compactors_file_size(&self) -> u64 {
+        self.figures.compactors.file_size
+    }
+
+    /// Returns the total number of indexes defined for the collection,
+    /// including the pre-defined indexes (e.g. primary index).
+    pub fn indexes_count(&self) -> u64 {
+        self.figures.indexes.count
+    }
+
+    /// Returns the total memory allocated for indexes in bytes.
+    pub fn indexes_size(&self) -> u64 {
+        self.figures.indexes.size
+    }
+
+    /// Returns whether a compaction is currently running for the
+    /// collection.
+    pub fn is_compaction_running(&self) -> bool {
+        self.figures.compaction_status.running
+    }
+
+    /// Returns a textual message describing the current or last compaction
+    /// status.
+    pub fn compaction_status_message(&self) -> &str {
+        &self.figures.compaction_status.message
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Figures {
+    alive: DocumentsFigures,
+    dead: DeadDocumentsFigures,
+    datafiles: DatafilesFigures,
+    journals: DatafilesFigures,
+    compactors: DatafilesFigures,
+    indexes: IndexesFigures,
+    #[serde(rename = "compactionStatus")]
+    compaction_status: CompactionStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DocumentsFigures {
+    count: u64,
+    size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DeadDocumentsFigures {
+    count: u64,
+    size: u64,
+    deletion: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DatafilesFigures {
+    count: u64,
+    #[serde(rename = "fileSize")]
+    file_size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexesFigures {
+    count: u64,
+    size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CompactionStatus {
+    running: bool,
+    message: String,
+}
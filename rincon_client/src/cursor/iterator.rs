@@ -0,0 +1,140 @@
+//! A `Stream` adapter over `CreateCursor` that transparently fetches
+//! follow-up batches.
+//!
+//! `CreateCursor` alone only returns the first batch of a query's result,
+//! leaving the caller to drive `ReadNextBatchFromCursor` by hand as long as
+//! `Cursor::has_more()` is `true`. `CursorIterator` does that bookkeeping,
+//! so a result set that does not fit into a single batch can be consumed
+//! one item at a time without buffering it all in memory.
+
+use std::vec;
+
+use futures::{Async, Future, Poll, Stream};
+use serde::de::DeserializeOwned;
+
+use rincon_core::api::connector::{Connector, Error, FutureResult};
+use super::methods::{CreateCursor, DeleteCursor, ReadNextBatchFromCursor};
+use super::types::NewCursor;
+
+enum State<T, C: Connector> {
+    Fetching(FutureResult<CreateCursor<T>>),
+    FetchingNextBatch(FutureResult<ReadNextBatchFromCursor<T>>),
+    Draining {
+        items: vec::IntoIter<T>,
+        cursor_id: Option<String>,
+        has_more: bool,
+    },
+    Exhausted,
+}
+
+/// A `Stream` that yields the items of an AQL query one at a time,
+/// transparently issuing `ReadNextBatchFromCursor` requests to fetch
+/// further batches as the local buffer empties.
+pub struct CursorIterator<'a, T, C: 'a + Connector> {
+    connector: &'a C,
+    state: State<T, C>,
+}
+
+impl<'a, T, C> CursorIterator<'a, T, C>
+    where T: DeserializeOwned, C: 'a + Connector
+{
+    /// Starts the given query and returns a `CursorIterator` over its
+    /// result.
+    pub fn from_query(connector: &'a C, new_cursor: NewCursor) -> Self {
+        CursorIterator {
+            connector,
+            state: State::Fetching(connector.execute(CreateCursor::new(new_cursor))),
+        }
+    }
+
+    /// Returns the id of the underlying server-side cursor, if the server
+    /// has kept one open because more results are pending.
+    ///
+    /// Returns `None` before the first batch has been fetched, and once
+    /// the cursor has been fully drained or explicitly deleted.
+    pub fn cursor_id(&self) -> Option<&str> {
+        match self.state {
+            State::Draining { ref cursor_id, .. } => cursor_id.as_ref().map(String::as_str),
+            _ => None,
+        }
+    }
+
+    /// Explicitly disposes of the underlying server-side cursor, if one is
+    /// still open.
+    ///
+    /// Use this to release server-side resources when abandoning iteration
+    /// before the cursor has been fully drained. There is no automatic
+    /// drop-time equivalent: running a cleanup request to completion from
+    /// `Drop` would require this type to own an executor, which it does
+    /// not, so callers that stop iterating early are responsible for
+    /// calling this themselves.
+    pub fn delete(&mut self) -> Option<FutureResult<DeleteCursor>> {
+        match self.state {
+            State::Draining { ref mut cursor_id, .. } => {
+                cursor_id.take().map(|id| self.connector.execute(DeleteCursor::new(id)))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<'a, T, C> Stream for CursorIterator<'a, T, C>
+    where T: DeserializeOwned, C: 'a + Connector
+{
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<T>, Self::Error> {
+        loop {
+            match self.state {
+                State::Fetching(ref mut future) => {
+                    let cursor = match future.poll()? {
+                        Async::Ready(cursor) => cursor,
+                        Async::NotReady => return Ok(Async::NotReady),
+                    };
+                    let cursor_id = cursor.id().map(str::to_owned);
+                    let has_more = cursor.has_more();
+                    self.state = State::Draining {
+                        items: cursor.into_result().into_iter(),
+                        cursor_id,
+                        has_more,
+                    };
+                },
+                State::FetchingNextBatch(ref mut future) => {
+                    let cursor = match future.poll()? {
+                        Async::Ready(cursor) => cursor,
+                        Async::NotReady => return Ok(Async::NotReady),
+                    };
+                    let cursor_id = cursor.id().map(str::to_owned);
+                    let has_more = cursor.has_more();
+                    self.state = State::Draining {
+                        items: cursor.into_result().into_iter(),
+                        cursor_id,
+                        has_more,
+                    };
+                },
+                State::Draining { ref mut items, ref cursor_id, has_more } => {
+                    if let Some(item) = items.next() {
+                        return Ok(Async::Ready(Some(item)));
+                    }
+                    // Per the server protocol, `has_more` only promises a
+                    // follow-up batch if a cursor id was also given to fetch
+                    // it from; stop gracefully in either case rather than
+                    // trusting the server to always pair the two.
+                    if has_more {
+                        if let Some(ref cursor_id) = *cursor_id {
+                            let future = self.connector.execute(
+                                ReadNextBatchFromCursor::new(cursor_id.clone()));
+                            self.state = State::FetchingNextBatch(future);
+                            continue;
+                        }
+                    }
+                    self.state = State::Exhausted;
+                },
+                State::Exhausted => {
+                    return Ok(Async::Ready(None));
+                },
+            }
+        }
+    }
+}
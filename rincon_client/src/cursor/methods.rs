@@ -0,0 +1,200 @@
+//! Methods for querying ArangoDB with AQL via the cursor API.
+
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use rincon_core::api::method::{Method, Operation, Parameters, Prepare, RpcReturnType};
+use rincon_core::arango::protocol::{FIELD_CODE, PATH_API_CURSOR};
+use super::types::*;
+
+/// Executes an AQL query and returns the first batch of its result as a
+/// `Cursor`.
+///
+/// If the cursor's `has_more()` returns `true`, the remaining batches must
+/// be fetched with `ReadNextBatchFromCursor` using the cursor's id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateCursor<T> {
+    new_cursor: NewCursor,
+    result_type: PhantomData<T>,
+}
+
+impl<T> CreateCursor<T> {
+    /// Constructs a new instance of the `CreateCursor` method for the
+    /// given query parameters.
+    pub fn new(new_cursor: NewCursor) -> Self {
+        CreateCursor {
+            new_cursor,
+            result_type: PhantomData,
+        }
+    }
+
+    /// Constructs a new instance of the `CreateCursor` method that
+    /// executes the given AQL query string.
+    pub fn from_query<Q>(query: Q) -> Self
+        where Q: Into<String>
+    {
+        CreateCursor::new(NewCursor::new(query))
+    }
+
+    /// Returns the parameters that are going to be used to create the
+    /// cursor.
+    pub fn new_cursor(&self) -> &NewCursor {
+        &self.new_cursor
+    }
+}
+
+impl<T> Method for CreateCursor<T>
+    where T: DeserializeOwned
+{
+    type Result = Cursor<T>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for CreateCursor<T> {
+    type Content = NewCursor;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_CURSOR)
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.new_cursor)
+    }
+}
+
+/// Fetches the next batch of an already created cursor.
+///
+/// Use this repeatedly, as long as `Cursor::has_more()` returns `true`,
+/// to drain a result set that did not fit into a single batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadNextBatchFromCursor<T> {
+    cursor_id: String,
+    result_type: PhantomData<T>,
+}
+
+impl<T> ReadNextBatchFromCursor<T> {
+    /// Constructs a new instance of the `ReadNextBatchFromCursor` method
+    /// for the cursor with the given id.
+    pub fn new<Id>(cursor_id: Id) -> Self
+        where Id: Into<String>
+    {
+        ReadNextBatchFromCursor {
+            cursor_id: cursor_id.into(),
+            result_type: PhantomData,
+        }
+    }
+
+    /// Returns the id of the cursor the next batch is fetched from.
+    pub fn cursor_id(&self) -> &str {
+        &self.cursor_id
+    }
+}
+
+impl<T> Method for ReadNextBatchFromCursor<T>
+    where T: DeserializeOwned
+{
+    type Result = Cursor<T>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for ReadNextBatchFromCursor<T> {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Modify
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_CURSOR) + "/" + &self.cursor_id
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
+/// Explicitly disposes of a cursor on the server, releasing its resources
+/// before it would otherwise expire on its own.
+///
+/// Use this to clean up a cursor that is abandoned before `has_more()`
+/// becomes `false`, e.g. because the caller stopped iterating early.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteCursor {
+    cursor_id: String,
+}
+
+impl DeleteCursor {
+    /// Constructs a new instance of the `DeleteCursor` method for the
+    /// cursor with the given id.
+    pub fn new<Id>(cursor_id: Id) -> Self
+        where Id: Into<String>
+    {
+        DeleteCursor {
+            cursor_id: cursor_id.into(),
+        }
+    }
+
+    /// Returns the id of the cursor that is going to be deleted.
+    pub fn cursor_id(&self) -> &str {
+        &self.cursor_id
+    }
+}
+
+impl Method for DeleteCursor {
+    type Result = ();
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for DeleteCursor {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Delete
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_CURSOR) + "/" + &self.cursor_id
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
@@ -0,0 +1,5 @@
+//! Types and methods for querying ArangoDB with AQL via the cursor API.
+
+pub mod iterator;
+pub mod methods;
+pub mod types;
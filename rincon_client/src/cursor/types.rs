@@ -0,0 +1,92 @@
+/// The parameters used to create a new cursor by executing an AQL query.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NewCursor {
+    query: String,
+    #[serde(rename = "bindVars", skip_serializing_if = "Option::is_none")]
+    bind_vars: Option<::serde_json::Value>,
+    #[serde(rename = "batchSize", skip_serializing_if = "Option::is_none")]
+    batch_size: Option<u32>,
+}
+
+impl NewCursor {
+    /// Constructs a new instance of `NewCursor` for the given AQL query
+    /// string, without bind variables and using the server's default
+    /// batch size.
+    pub fn new<Q>(query: Q) -> Self
+        where Q: Into<String>
+    {
+        NewCursor {
+            query: query.into(),
+            bind_vars: None,
+            batch_size: None,
+        }
+    }
+
+    /// Sets the bind variables used to parameterize the query.
+    pub fn with_bind_vars(mut self, bind_vars: ::serde_json::Value) -> Self {
+        self.bind_vars = Some(bind_vars);
+        self
+    }
+
+    /// Sets the maximum number of result documents fetched per round-trip.
+    pub fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Returns the AQL query string that is going to be executed.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the bind variables used to parameterize the query, if any.
+    pub fn bind_vars(&self) -> Option<&::serde_json::Value> {
+        self.bind_vars.as_ref()
+    }
+
+    /// Returns the maximum number of result documents fetched per
+    /// round-trip, if explicitly set.
+    pub fn batch_size(&self) -> Option<u32> {
+        self.batch_size
+    }
+}
+
+/// One page of the result of an AQL query, as returned by `CreateCursor`
+/// and the follow-up `ReadNextBatchFromCursor` requests.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Cursor<T> {
+    id: Option<String>,
+    result: Vec<T>,
+    #[serde(rename = "hasMore")]
+    has_more: bool,
+    count: Option<u64>,
+}
+
+impl<T> Cursor<T> {
+    /// Returns the id of this cursor, if the server kept it open because
+    /// more results are available.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(String::as_str)
+    }
+
+    /// Returns whether more results are available on the server that have
+    /// not been fetched as part of this batch yet.
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// Returns the total number of results of the query, if requested.
+    pub fn count(&self) -> Option<u64> {
+        self.count
+    }
+
+    /// Returns the documents fetched as part of this batch.
+    pub fn result(&self) -> &[T] {
+        &self.result
+    }
+
+    /// Consumes this batch and returns its documents.
+    pub fn into_result(self) -> Vec<T> {
+        self.result
+    }
+}
@@ -0,0 +1,73 @@
+//! Serde adapters for enum fields that are stored using an external code
+//! representation instead of serde's default variant-name serialization.
+//!
+//! Many existing ArangoDB collections store enum-like fields as legacy
+//! integer discriminants or differently-cased string tokens rather than
+//! the Rust variant name serde would produce by default. `Coded<E>` lets
+//! such a field round-trip through a user-supplied `EnumCode` mapping
+//! while the rest of the containing document is (de)serialized normally
+//! via `NewDocument`/`GetDocument` as usual.
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de;
+
+/// Maps an enum's variants to and from an external code representation.
+///
+/// Implement this trait for the enum type to describe how each of its
+/// variants corresponds to one value of `Code`, e.g. a legacy integer
+/// discriminant or an uppercased string token.
+pub trait EnumCode: Sized {
+    /// The external representation the enum is encoded as, e.g. `u8` or
+    /// `String`.
+    type Code: Serialize + for<'de> Deserialize<'de> + fmt::Debug;
+
+    /// Returns the code that represents this variant.
+    fn to_code(&self) -> Self::Code;
+
+    /// Resolves a variant from the given code, or `None` if the code is
+    /// not recognized.
+    fn from_code(code: &Self::Code) -> Option<Self>;
+}
+
+/// A transparent wrapper around an enum `E` that (de)serializes it through
+/// its `EnumCode` mapping instead of its variant name.
+///
+/// Use this to wrap an enum-typed field of a document struct, e.g.
+/// `gender: Coded<Gender>` instead of `gender: Gender`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Coded<E>(pub E);
+
+impl<E> Coded<E> {
+    /// Unwraps this wrapper and returns the contained enum value.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> From<E> for Coded<E> {
+    fn from(value: E) -> Self {
+        Coded(value)
+    }
+}
+
+impl<E: EnumCode> Serialize for Coded<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        self.0.to_code().serialize(serializer)
+    }
+}
+
+impl<'de, E: EnumCode> Deserialize<'de> for Coded<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let code = E::Code::deserialize(deserializer)?;
+        match E::from_code(&code) {
+            Some(value) => Ok(Coded(value)),
+            None => Err(de::Error::custom(format!("unrecognized enum code: {:?}", code))),
+        }
+    }
+}
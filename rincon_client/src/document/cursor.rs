@@ -0,0 +1,77 @@
+//! Streaming iteration over all documents of a collection with automatic
+//! paging.
+//!
+//! The insert/get methods all operate on documents whose id is already
+//! known. `ListDocuments` instead walks an entire collection, transparently
+//! fetching further pages of results as the returned `Stream` is polled
+//! (via `CursorIterator`), so large collections can be processed without
+//! loading them into a `Vec` first.
+
+use serde::de::DeserializeOwned;
+
+use rincon_core::api::connector::Connector;
+use cursor::iterator::CursorIterator;
+use cursor::types::NewCursor;
+
+/// Builds a `DocumentCursor` stream that iterates over the documents of a
+/// collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListDocuments {
+    collection_name: String,
+    filter: Option<String>,
+    batch_size: u32,
+}
+
+impl ListDocuments {
+    /// Constructs a new instance of `ListDocuments` that will iterate over
+    /// all documents of the collection with the given name.
+    pub fn new<N>(collection_name: N) -> Self
+        where N: Into<String>
+    {
+        ListDocuments {
+            collection_name: collection_name.into(),
+            filter: None,
+            batch_size: 1000,
+        }
+    }
+
+    /// Sets an additional AQL `FILTER` expression that is applied to each
+    /// document, referencing the document as `doc`.
+    ///
+    /// For example `.with_filter("doc.active == true")`.
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+        where F: Into<String>
+    {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Sets the number of documents fetched per round-trip to the server.
+    pub fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Starts the query and returns a `Stream` over the matching
+    /// documents, deserialized into `T`, automatically fetching further
+    /// pages as needed.
+    pub fn call<'a, T, C>(&self, connector: &'a C) -> DocumentCursor<'a, T, C>
+        where T: DeserializeOwned, C: Connector
+    {
+        let mut query = format!("FOR doc IN `{}`", self.collection_name);
+        if let Some(ref filter) = self.filter {
+            query.push_str(" FILTER ");
+            query.push_str(filter);
+        }
+        query.push_str(" RETURN doc");
+
+        let new_cursor = NewCursor::new(query).with_batch_size(self.batch_size);
+        CursorIterator::from_query(connector, new_cursor)
+    }
+}
+
+/// A `Stream` that yields the documents of a collection one at a time.
+///
+/// This is an alias for `CursorIterator`, kept under this name for callers
+/// that arrived here via `ListDocuments`.
+pub type DocumentCursor<'a, T, C> = CursorIterator<'a, T, C>;
@@ -0,0 +1,274 @@
+//! A typed error taxonomy for common document-operation failures.
+//!
+//! Single-document methods like `GetDocument::with_if_match` report a
+//! failure through the generic `connection::Error`, whose `ApiError`
+//! carries the HTTP status and a raw `ErrorCode`. `DocumentError`
+//! classifies the common cases of such an `ApiError`, e.g. a revision
+//! conflict (412) or a not-found error (404), via
+//! `DocumentError::from_api_error` or `GetDocument::classify_error`, so
+//! that an optimistic-concurrency retry loop around `ReplaceDocument` or
+//! `ModifyDocument` can match on `DocumentError::RevisionConflict { .. }`
+//! instead of hard-coding status codes.
+//!
+//! `MethodError` classifies the same kind of failures for the per-element
+//! results of a batch write: `InsertDocuments` and the other bulk methods
+//! return a `Vec<Result<_, MethodError>>`. It additionally distinguishes a unique-constraint
+//! violation, the failure bulk inserts most commonly run into, and exposes
+//! the violated index and fields rather than leaving callers to match on
+//! the server's error message.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use rincon_core::api::ErrorCode;
+use rincon_core::api::types::ApiError;
+
+use super::types::{DocumentId, Revision};
+
+/// A typed classification of the most common failures encountered while
+/// executing document methods.
+///
+/// This is constructed from the HTTP status code and `ErrorCode` of an
+/// `ApiError` response. Failures that do not match one of the known cases
+/// are preserved as `Other` so that no information is lost.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentError {
+    /// The document identified by `id` does not exist.
+    DocumentNotFound {
+        /// The id of the document that could not be found.
+        id: DocumentId,
+    },
+    /// The collection with the given name does not exist.
+    CollectionNotFound {
+        /// The name of the collection that could not be found.
+        name: String,
+    },
+    /// The revision of the stored document does not match the revision
+    /// that was expected by the caller, e.g. via `If-Match` or the
+    /// revision carried in a `ReplaceDocument`/`UpdateDocument` request.
+    RevisionConflict {
+        /// The revision that was expected by the caller, if known.
+        expected: Option<Revision>,
+        /// The revision that is currently stored, if known.
+        actual: Option<Revision>,
+    },
+    /// The caller is not authorized to perform the requested operation.
+    Unauthorized,
+    /// A failure that does not map to one of the classified cases above.
+    Other {
+        /// The raw error code returned by the server.
+        code: ErrorCode,
+        /// The raw error message returned by the server.
+        message: String,
+    },
+}
+
+impl DocumentError {
+    /// Classifies the given `ApiError` into a `DocumentError`.
+    ///
+    /// `id` is the id of the document the failing request was about and is
+    /// used both to fill `DocumentNotFound` and, since a `DocumentId`
+    /// always carries its collection name, to fill `CollectionNotFound`.
+    pub fn from_api_error(error: &ApiError, id: DocumentId) -> Self {
+        match (error.status_code(), error.error_code()) {
+            (404, ErrorCode::ArangoDocumentNotFound) =>
+                DocumentError::DocumentNotFound { id },
+            (404, ErrorCode::ArangoCollectionNotFound) =>
+                DocumentError::CollectionNotFound {
+                    name: id.collection_name().to_owned(),
+                },
+            (412, ErrorCode::ArangoConflict) =>
+                DocumentError::RevisionConflict {
+                    expected: None,
+                    actual: None,
+                },
+            (401, _) | (403, _) => DocumentError::Unauthorized,
+            _ => DocumentError::Other {
+                code: error.error_code(),
+                message: error.message().to_owned(),
+            },
+        }
+    }
+
+    /// Classifies the given `ApiError` into a `DocumentError` with an
+    /// explicitly known expected and actual revision.
+    ///
+    /// Use this over `from_api_error` when the caller already knows the
+    /// revision it expected (e.g. the one passed to `with_if_match`) so
+    /// that a `RevisionConflict` carries both sides of the mismatch.
+    pub fn from_api_error_with_revisions(
+        error: &ApiError,
+        id: DocumentId,
+        expected: Option<Revision>,
+        actual: Option<Revision>,
+    ) -> Self {
+        match (error.status_code(), error.error_code()) {
+            (412, ErrorCode::ArangoConflict) =>
+                DocumentError::RevisionConflict { expected, actual },
+            _ => DocumentError::from_api_error(error, id),
+        }
+    }
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DocumentError::DocumentNotFound { ref id } =>
+                write!(f, "document not found: {}", id),
+            DocumentError::CollectionNotFound { ref name } =>
+                write!(f, "collection not found: {}", name),
+            DocumentError::RevisionConflict { .. } =>
+                write!(f, "document revision conflict"),
+            DocumentError::Unauthorized =>
+                write!(f, "not authorized"),
+            DocumentError::Other { ref message, .. } =>
+                write!(f, "{}", message),
+        }
+    }
+}
+
+impl StdError for DocumentError {
+    fn description(&self) -> &str {
+        match *self {
+            DocumentError::DocumentNotFound { .. } => "document not found",
+            DocumentError::CollectionNotFound { .. } => "collection not found",
+            DocumentError::RevisionConflict { .. } => "document revision conflict",
+            DocumentError::Unauthorized => "not authorized",
+            DocumentError::Other { ref message, .. } => message,
+        }
+    }
+}
+
+/// A typed classification of the failures that can be reported for a
+/// single element of a batch write, e.g. one entry of the
+/// `Vec<Result<_, MethodError>>` returned by `InsertDocuments`,
+/// `ReplaceDocuments`, `ModifyDocuments` or `RemoveDocuments`.
+///
+/// Unlike `DocumentError`, this is not constructed with a `DocumentId` of
+/// its own, since a batch failure is not always attributable to a single
+/// known id (e.g. `UniqueConstraintViolated` names the *other* document
+/// that already holds the conflicting key). Callers that already know the
+/// id of the failing element can still obtain it from the request they
+/// sent, by index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MethodError {
+    /// A unique index was violated by this element of the batch.
+    ///
+    /// `index`, `index_type` and `fields` are parsed on a best-effort
+    /// basis from the server's human-readable error message, since
+    /// ArangoDB does not report them as separate fields. Any part that
+    /// could not be recognized is left empty.
+    UniqueConstraintViolated {
+        /// The id of the violated index, e.g. `"0"`.
+        index: Option<String>,
+        /// The type of the violated index, e.g. `"primary"` or `"hash"`.
+        index_type: Option<String>,
+        /// The fields covered by the violated index.
+        fields: Vec<String>,
+    },
+    /// The document addressed by this element does not exist.
+    DocumentNotFound,
+    /// The revision expected by the caller did not match the revision
+    /// currently stored.
+    PreconditionFailed {
+        /// The revision the caller expected, if it was supplied as part
+        /// of the request that produced this element.
+        expected_revision: Option<Revision>,
+    },
+    /// A failure that does not map to one of the classified cases above.
+    Other {
+        /// The raw error code returned by the server.
+        code: ErrorCode,
+        /// The raw error message returned by the server.
+        message: String,
+    },
+}
+
+impl MethodError {
+    /// Classifies the given `ApiError` into a `MethodError`.
+    pub fn from_api_error(error: &ApiError) -> Self {
+        MethodError::from_api_error_with_expected_revision(error, None)
+    }
+
+    /// Classifies the given `ApiError` into a `MethodError`, attaching the
+    /// revision the caller expected in case it turns out to be a
+    /// `PreconditionFailed`.
+    pub fn from_api_error_with_expected_revision(
+        error: &ApiError,
+        expected_revision: Option<Revision>,
+    ) -> Self {
+        match (error.status_code(), error.error_code()) {
+            (_, ErrorCode::ArangoUniqueConstraintViolated) => {
+                let (index, index_type, fields) =
+                    parse_unique_constraint_violation(error.message());
+                MethodError::UniqueConstraintViolated { index, index_type, fields }
+            },
+            (404, ErrorCode::ArangoDocumentNotFound) =>
+                MethodError::DocumentNotFound,
+            (412, ErrorCode::ArangoConflict) =>
+                MethodError::PreconditionFailed { expected_revision },
+            _ => MethodError::Other {
+                code: error.error_code(),
+                message: error.message().to_owned(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for MethodError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MethodError::UniqueConstraintViolated { ref fields, .. } =>
+                write!(f, "unique constraint violated over fields {:?}", fields),
+            MethodError::DocumentNotFound =>
+                write!(f, "document not found"),
+            MethodError::PreconditionFailed { .. } =>
+                write!(f, "document revision conflict"),
+            MethodError::Other { ref message, .. } =>
+                write!(f, "{}", message),
+        }
+    }
+}
+
+impl StdError for MethodError {
+    fn description(&self) -> &str {
+        match *self {
+            MethodError::UniqueConstraintViolated { .. } => "unique constraint violated",
+            MethodError::DocumentNotFound => "document not found",
+            MethodError::PreconditionFailed { .. } => "document revision conflict",
+            MethodError::Other { ref message, .. } => message,
+        }
+    }
+}
+
+/// Parses the `index`, `index_type` and `fields` out of an ArangoDB
+/// unique-constraint-violation message, which is of the form
+/// `"unique constraint violated - in index 0 of type primary over [\"_key\"]"`.
+///
+/// This is inherently best-effort: it relies on the wording of a
+/// human-readable message rather than a structured field, so a server
+/// that phrases the message differently simply yields `None`/empty parts
+/// here rather than an error.
+fn parse_unique_constraint_violation(message: &str) -> (Option<String>, Option<String>, Vec<String>) {
+    let index = extract_between(message, " in index ", " of type ").map(str::to_owned);
+    let index_type = extract_between(message, " of type ", " over ").map(str::to_owned);
+    let fields = message.find(" over ")
+        .map(|pos| parse_field_list(&message[pos + " over ".len()..]))
+        .unwrap_or_default();
+    (index, index_type, fields)
+}
+
+fn extract_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let after_start = s.find(start).map(|pos| pos + start.len())?;
+    let rest = &s[after_start..];
+    rest.find(end).map(|end_pos| &rest[..end_pos])
+}
+
+fn parse_field_list(s: &str) -> Vec<String> {
+    s.trim()
+        .trim_matches(|c| c == '[' || c == ']')
+        .split(',')
+        .map(|field| field.trim().trim_matches('"').to_owned())
+        .filter(|field| !field.is_empty())
+        .collect()
+}
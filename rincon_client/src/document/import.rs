@@ -0,0 +1,225 @@
+//! Streaming bulk import of documents from newline-delimited JSON (NDJSON).
+//!
+//! `InsertDocuments` requires the whole `Vec<NewDocument<T>>` to be held in
+//! memory before it can be sent to the server. The types in this module let
+//! callers stream an arbitrarily large NDJSON source into ArangoDB instead,
+//! by accumulating it into bounded batches so memory usage stays flat
+//! regardless of the size of the input.
+
+use std::io::{self, BufRead};
+
+use rincon_core::api::method::{Method, Operation, Parameters, Prepare, RpcReturnType};
+use rincon_core::api::types::JsonString;
+use rincon_core::arango::protocol::{FIELD_CODE, PARAM_COLLECTION, PARAM_DETAILS,
+    PARAM_ON_DUPLICATE, PARAM_TYPE, PATH_API_IMPORT};
+use super::types::ImportSummary;
+
+/// The default number of documents accumulated into one import batch
+/// before it is shipped to the server.
+pub const DEFAULT_IMPORT_BATCH_SIZE: usize = 1000;
+
+/// Controls how the server handles a document whose `_key` already exists
+/// in the target collection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OnDuplicate {
+    /// Reject the import of the conflicting document. This is the default.
+    Error,
+    /// Merge the new document into the existing one, like a partial update.
+    Update,
+    /// Replace the existing document with the new one entirely.
+    Replace,
+    /// Silently keep the existing document unchanged.
+    Ignore,
+}
+
+impl OnDuplicate {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            OnDuplicate::Error => "error",
+            OnDuplicate::Update => "update",
+            OnDuplicate::Replace => "replace",
+            OnDuplicate::Ignore => "ignore",
+        }
+    }
+}
+
+/// Imports one bounded batch of documents into the collection with the
+/// given name.
+///
+/// This is the low-level, single-request building block used by
+/// `NdjsonImportBatches` to ship an NDJSON source to the server in bounded
+/// batches rather than as one large request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportDocumentBatch {
+    collection_name: String,
+    documents: Vec<JsonString>,
+    on_duplicate: OnDuplicate,
+}
+
+impl ImportDocumentBatch {
+    /// Constructs a new instance of the `ImportDocumentBatch` method that
+    /// will import the given documents into the collection with the given
+    /// name, rejecting any document whose `_key` already exists.
+    pub fn new<N>(collection_name: N, documents: Vec<JsonString>) -> Self
+        where N: Into<String>
+    {
+        ImportDocumentBatch {
+            collection_name: collection_name.into(),
+            documents,
+            on_duplicate: OnDuplicate::Error,
+        }
+    }
+
+    /// Sets how the server shall handle documents whose `_key` already
+    /// exists in the target collection.
+    pub fn with_on_duplicate(mut self, on_duplicate: OnDuplicate) -> Self {
+        self.on_duplicate = on_duplicate;
+        self
+    }
+
+    /// Returns the name of the collection the documents are imported into.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the documents that are going to be imported by this batch.
+    pub fn documents(&self) -> &[JsonString] {
+        &self.documents
+    }
+
+    /// Returns how the server will handle documents whose `_key` already
+    /// exists in the target collection.
+    pub fn on_duplicate(&self) -> OnDuplicate {
+        self.on_duplicate
+    }
+}
+
+impl Method for ImportDocumentBatch {
+    type Result = ImportSummary;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for ImportDocumentBatch {
+    type Content = Vec<JsonString>;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_IMPORT)
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(4);
+        params.insert(PARAM_COLLECTION, self.collection_name.clone());
+        params.insert(PARAM_TYPE, "list");
+        params.insert(PARAM_DETAILS, true);
+        if self.on_duplicate != OnDuplicate::Error {
+            params.insert(PARAM_ON_DUPLICATE, self.on_duplicate.as_str());
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.documents)
+    }
+}
+
+/// Reads an NDJSON source line by line and lazily yields bounded
+/// `ImportDocumentBatch` methods, so that a large dataset never needs to be
+/// held in memory as a single `Vec`.
+///
+/// Each yielded method is a plain `Method`/`Prepare` value like any other in
+/// this crate and is dispatched by the caller through its connection, so
+/// the summaries of the individual batches can be merged with
+/// `ImportSummary::merge` as they complete.
+pub struct NdjsonImportBatches<R> {
+    collection_name: String,
+    reader: R,
+    on_duplicate: OnDuplicate,
+    batch_size: usize,
+    line: String,
+    done: bool,
+}
+
+impl<R: BufRead> NdjsonImportBatches<R> {
+    /// Constructs a new instance of `NdjsonImportBatches` that will read
+    /// newline-delimited JSON documents from the given reader and batch
+    /// them for import into the collection with the given name.
+    pub fn new<N>(collection_name: N, reader: R) -> Self
+        where N: Into<String>
+    {
+        NdjsonImportBatches {
+            collection_name: collection_name.into(),
+            reader,
+            on_duplicate: OnDuplicate::Error,
+            batch_size: DEFAULT_IMPORT_BATCH_SIZE,
+            line: String::new(),
+            done: false,
+        }
+    }
+
+    /// Sets how the server shall handle documents whose `_key` already
+    /// exists in the target collection.
+    pub fn with_on_duplicate(mut self, on_duplicate: OnDuplicate) -> Self {
+        self.on_duplicate = on_duplicate;
+        self
+    }
+
+    /// Sets the maximum number of documents accumulated into one batch
+    /// before it is yielded.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub(super) fn next_batch(&mut self) -> io::Result<Option<Vec<JsonString>>> {
+        let mut documents = Vec::with_capacity(self.batch_size);
+        while documents.len() < self.batch_size {
+            self.line.clear();
+            let bytes_read = self.reader.read_line(&mut self.line)?;
+            if bytes_read == 0 {
+                self.done = true;
+                break;
+            }
+            let trimmed = self.line.trim();
+            if !trimmed.is_empty() {
+                documents.push(JsonString::new(trimmed));
+            }
+        }
+        if documents.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(documents))
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for NdjsonImportBatches<R> {
+    type Item = io::Result<ImportDocumentBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_batch() {
+            Ok(Some(documents)) => Some(Ok(
+                ImportDocumentBatch::new(self.collection_name.clone(), documents)
+                    .with_on_duplicate(self.on_duplicate)
+            )),
+            Ok(None) => None,
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            },
+        }
+    }
+}
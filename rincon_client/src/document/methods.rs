@@ -0,0 +1,1437 @@
+//! Methods for managing documents.
+
+use std::marker::PhantomData;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use rincon_core::api::method::{Method, Operation, Parameters, Prepare, RpcReturnType};
+use rincon_core::api::types::ApiError;
+use rincon_core::arango::protocol::{FIELD_CODE, PARAM_IGNORE_REVS, PARAM_KEEP_NULL,
+    PARAM_MERGE_OBJECTS, PARAM_OVERWRITE_MODE, PARAM_RETURN_NEW, PARAM_RETURN_OLD,
+    PARAM_WAIT_FOR_SYNC, HEADER_IF_MATCH, HEADER_IF_NONE_MATCH, PATH_API_DOCUMENT};
+use super::error::{DocumentError, MethodError};
+use super::types::*;
+
+/// Creates (HTTP POST) a single new document in a collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertDocument<T> {
+    collection_name: String,
+    document: NewDocument<T>,
+    wait_for_sync: bool,
+    overwrite_mode: Option<OverwriteMode>,
+    keep_null: bool,
+    merge_objects: bool,
+    return_old: bool,
+}
+
+impl<T> InsertDocument<T> {
+    /// Constructs a new instance of the `InsertDocument` method that will
+    /// insert the given document into the collection with the given name.
+    pub fn new<N>(collection_name: N, document: NewDocument<T>) -> Self
+        where N: Into<String>
+    {
+        InsertDocument {
+            collection_name: collection_name.into(),
+            document,
+            wait_for_sync: false,
+            overwrite_mode: None,
+            keep_null: true,
+            merge_objects: true,
+            return_old: false,
+        }
+    }
+
+    /// Sets whether the client shall wait until the document has been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets how the server shall react if a document with the same `_key`
+    /// already exists, instead of failing with
+    /// `ErrorCode::ArangoUniqueConstraintViolated`.
+    pub fn with_overwrite_mode(mut self, overwrite_mode: OverwriteMode) -> Self {
+        self.overwrite_mode = Some(overwrite_mode);
+        self
+    }
+
+    /// Sets whether `null` values shall be kept in the stored document
+    /// when `overwrite_mode` is `Update`.
+    pub fn with_keep_null(mut self, keep_null: bool) -> Self {
+        self.keep_null = keep_null;
+        self
+    }
+
+    /// Sets whether object values shall be merged instead of replaced
+    /// when `overwrite_mode` is `Update`.
+    pub fn with_merge_objects(mut self, merge_objects: bool) -> Self {
+        self.merge_objects = merge_objects;
+        self
+    }
+
+    /// Sets whether the document as it was before being overwritten shall
+    /// be returned in the response, when `overwrite_mode` caused an
+    /// existing document to be replaced or updated.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Returns the name of the collection the document is inserted into.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the document that is going to be inserted.
+    pub fn document(&self) -> &NewDocument<T> {
+        &self.document
+    }
+}
+
+impl<T> Method for InsertDocument<T>
+    where T: DeserializeOwned
+{
+    type Result = InsertedDocument<T>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for InsertDocument<T>
+    where T: Serialize
+{
+    type Content = NewDocument<T>;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_DOCUMENT) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(5);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if let Some(overwrite_mode) = self.overwrite_mode {
+            params.insert(PARAM_OVERWRITE_MODE, overwrite_mode.as_str());
+            if overwrite_mode == OverwriteMode::Update {
+                params.insert(PARAM_KEEP_NULL, self.keep_null);
+                params.insert(PARAM_MERGE_OBJECTS, self.merge_objects);
+            }
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.document)
+    }
+}
+
+/// Creates (HTTP POST) a single new document in a collection and always
+/// returns the inserted document.
+///
+/// This is a convenience wrapper around `InsertDocument` that sets
+/// `return_new` to `true`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertDocumentReturnNew<T> {
+    inner: InsertDocument<T>,
+}
+
+impl<T> InsertDocumentReturnNew<T> {
+    /// Constructs a new instance of the `InsertDocumentReturnNew` method
+    /// that will insert the given document into the collection with the
+    /// given name.
+    pub fn new<N>(collection_name: N, document: NewDocument<T>) -> Self
+        where N: Into<String>
+    {
+        InsertDocumentReturnNew {
+            inner: InsertDocument::new(collection_name, document),
+        }
+    }
+
+    /// Sets whether the client shall wait until the document has been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.inner = self.inner.with_force_wait_for_sync(wait_for_sync);
+        self
+    }
+
+    /// Sets how the server shall react if a document with the same `_key`
+    /// already exists, instead of failing with
+    /// `ErrorCode::ArangoUniqueConstraintViolated`.
+    pub fn with_overwrite_mode(mut self, overwrite_mode: OverwriteMode) -> Self {
+        self.inner = self.inner.with_overwrite_mode(overwrite_mode);
+        self
+    }
+
+    /// Sets whether the document as it was before being overwritten shall
+    /// be returned in the response, when `overwrite_mode` caused an
+    /// existing document to be replaced or updated.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.inner = self.inner.with_return_old(return_old);
+        self
+    }
+}
+
+impl<T> Method for InsertDocumentReturnNew<T>
+    where T: DeserializeOwned
+{
+    type Result = InsertedDocumentReturnNew<T, T>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for InsertDocumentReturnNew<T>
+    where T: Serialize
+{
+    type Content = NewDocument<T>;
+
+    fn operation(&self) -> Operation {
+        self.inner.operation()
+    }
+
+    fn path(&self) -> String {
+        self.inner.path()
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = self.inner.parameters();
+        params.insert(PARAM_RETURN_NEW, true);
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        self.inner.header()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        self.inner.content()
+    }
+}
+
+/// Creates (HTTP POST) multiple new documents of a collection in a single
+/// request.
+///
+/// As with `ReplaceDocuments`, the response is a parallel array where each
+/// element is either a document header or an embedded error object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertDocuments<T> {
+    collection_name: String,
+    documents: Vec<NewDocument<T>>,
+    wait_for_sync: bool,
+    overwrite_mode: Option<OverwriteMode>,
+    keep_null: bool,
+    merge_objects: bool,
+    return_old: bool,
+}
+
+impl<T> InsertDocuments<T> {
+    /// Constructs a new instance of the `InsertDocuments` method that will
+    /// insert the given documents into the collection with the given name.
+    pub fn new<N, Documents>(collection_name: N, documents: Documents) -> Self
+        where N: Into<String>, Documents: IntoIterator<Item=NewDocument<T>>
+    {
+        InsertDocuments {
+            collection_name: collection_name.into(),
+            documents: documents.into_iter().collect(),
+            wait_for_sync: false,
+            overwrite_mode: None,
+            keep_null: true,
+            merge_objects: true,
+            return_old: false,
+        }
+    }
+
+    /// Sets whether the client shall wait until the documents have been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets how the server shall react if a document with the same `_key`
+    /// already exists, instead of failing with
+    /// `ErrorCode::ArangoUniqueConstraintViolated`.
+    pub fn with_overwrite_mode(mut self, overwrite_mode: OverwriteMode) -> Self {
+        self.overwrite_mode = Some(overwrite_mode);
+        self
+    }
+
+    /// Sets whether `null` values shall be kept in the stored documents
+    /// when `overwrite_mode` is `Update`.
+    pub fn with_keep_null(mut self, keep_null: bool) -> Self {
+        self.keep_null = keep_null;
+        self
+    }
+
+    /// Sets whether object values shall be merged instead of replaced
+    /// when `overwrite_mode` is `Update`.
+    pub fn with_merge_objects(mut self, merge_objects: bool) -> Self {
+        self.merge_objects = merge_objects;
+        self
+    }
+
+    /// Sets whether documents as they were before being overwritten shall
+    /// be returned in the response, when `overwrite_mode` caused an
+    /// existing document to be replaced or updated.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Returns the name of the collection the documents are inserted into.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the documents that are going to be inserted.
+    pub fn documents(&self) -> &[NewDocument<T>] {
+        &self.documents
+    }
+}
+
+impl<T> Method for InsertDocuments<T>
+    where T: DeserializeOwned
+{
+    type Result = Vec<Result<InsertedDocument<T>, MethodError>>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for InsertDocuments<T>
+    where T: Serialize
+{
+    type Content = Vec<NewDocument<T>>;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_DOCUMENT) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(5);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if let Some(overwrite_mode) = self.overwrite_mode {
+            params.insert(PARAM_OVERWRITE_MODE, overwrite_mode.as_str());
+            if overwrite_mode == OverwriteMode::Update {
+                params.insert(PARAM_KEEP_NULL, self.keep_null);
+                params.insert(PARAM_MERGE_OBJECTS, self.merge_objects);
+            }
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.documents)
+    }
+}
+
+/// Creates (HTTP POST) multiple new documents of a collection in a single
+/// request and always returns the inserted documents.
+///
+/// This is a convenience wrapper around `InsertDocuments` that sets
+/// `return_new` to `true`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertDocumentsReturnNew<T> {
+    inner: InsertDocuments<T>,
+}
+
+impl<T> InsertDocumentsReturnNew<T> {
+    /// Constructs a new instance of the `InsertDocumentsReturnNew` method
+    /// that will insert the given documents into the collection with the
+    /// given name.
+    pub fn new<N, Documents>(collection_name: N, documents: Documents) -> Self
+        where N: Into<String>, Documents: IntoIterator<Item=NewDocument<T>>
+    {
+        InsertDocumentsReturnNew {
+            inner: InsertDocuments::new(collection_name, documents),
+        }
+    }
+
+    /// Sets whether the client shall wait until the documents have been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.inner = self.inner.with_force_wait_for_sync(wait_for_sync);
+        self
+    }
+
+    /// Sets how the server shall react if a document with the same `_key`
+    /// already exists, instead of failing with
+    /// `ErrorCode::ArangoUniqueConstraintViolated`.
+    pub fn with_overwrite_mode(mut self, overwrite_mode: OverwriteMode) -> Self {
+        self.inner = self.inner.with_overwrite_mode(overwrite_mode);
+        self
+    }
+
+    /// Sets whether documents as they were before being overwritten shall
+    /// be returned in the response, when `overwrite_mode` caused an
+    /// existing document to be replaced or updated.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.inner = self.inner.with_return_old(return_old);
+        self
+    }
+}
+
+impl<T> Method for InsertDocumentsReturnNew<T>
+    where T: DeserializeOwned
+{
+    type Result = Vec<Result<InsertedDocumentReturnNew<T, T>, MethodError>>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for InsertDocumentsReturnNew<T>
+    where T: Serialize
+{
+    type Content = Vec<NewDocument<T>>;
+
+    fn operation(&self) -> Operation {
+        self.inner.operation()
+    }
+
+    fn path(&self) -> String {
+        self.inner.path()
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = self.inner.parameters();
+        params.insert(PARAM_RETURN_NEW, true);
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        self.inner.header()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        self.inner.content()
+    }
+}
+
+/// Retrieves (HTTP GET) a single document identified by its id.
+///
+/// Resolves to `None` rather than a document in the one case the server
+/// itself signals as distinct from "fetched the document": a `304 Not
+/// Modified` response to a conditional request made via
+/// `with_if_none_match`. That response has no body, so it cannot be
+/// deserialized into a `Document<T>` the way an ordinary `200` can.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetDocument<T> {
+    id: DocumentId,
+    if_match: Option<String>,
+    if_none_match: Option<Revision>,
+    content_type: PhantomData<T>,
+}
+
+impl<T> GetDocument<T> {
+    /// Constructs a new instance of the `GetDocument` method that will get
+    /// the document identified by the given id.
+    pub fn new(id: DocumentId) -> Self {
+        GetDocument {
+            id,
+            if_match: None,
+            if_none_match: None,
+            content_type: PhantomData,
+        }
+    }
+
+    /// Sets a revision that the stored document must match, rejecting the
+    /// request with a precondition failed error otherwise.
+    pub fn with_if_match<R>(mut self, revision: R) -> Self
+        where R: Into<String>
+    {
+        self.if_match = Some(revision.into());
+        self
+    }
+
+    /// Sets a revision that, if it still matches the stored document,
+    /// causes the request to resolve to `Ok(None)` instead of fetching the
+    /// document again.
+    ///
+    /// This enables cheap client-side revision caching: callers hold on to
+    /// the last seen revision and only pay for the document body when it
+    /// has actually changed.
+    pub fn with_if_none_match(mut self, revision: Revision) -> Self {
+        self.if_none_match = Some(revision);
+        self
+    }
+
+    /// Returns the id of the document to get.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+
+    /// Classifies a failure returned for this method into a
+    /// `DocumentError`, treating the revision passed to `with_if_match`,
+    /// if any, as the expected revision of a `RevisionConflict`.
+    ///
+    /// Use this to turn the raw `ApiError` carried by `Error::ApiError`
+    /// into something a caller can `match` on, e.g. to retry on
+    /// `DocumentError::RevisionConflict` rather than comparing revision
+    /// strings or status codes by hand.
+    pub fn classify_error(&self, error: &ApiError) -> DocumentError {
+        DocumentError::from_api_error_with_revisions(
+            error,
+            self.id.clone(),
+            self.if_match.as_ref().map(|revision| Revision::new(revision.clone())),
+            None,
+        )
+    }
+}
+
+impl<T> Method for GetDocument<T>
+    where T: DeserializeOwned
+{
+    type Result = Option<Document<T>>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for GetDocument<T> {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Read
+    }
+
+    fn path(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        let mut header = Parameters::empty();
+        if let Some(ref if_match) = self.if_match {
+            header.insert(HEADER_IF_MATCH, if_match.clone());
+        }
+        if let Some(ref if_none_match) = self.if_none_match {
+            header.insert(HEADER_IF_NONE_MATCH, if_none_match.as_str().to_owned());
+        }
+        header
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
+/// Checks whether a document exists and, if so, returns its current
+/// revision.
+///
+/// This is the replacement for the former `GetDocumentHeader` method: it
+/// issues an HTTP `HEAD` request and never deserializes a response body.
+/// A `404` resolves to `None`, and a `200` resolves to `Some(revision)`
+/// parsed from the response's `Etag` header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentExists {
+    id: DocumentId,
+}
+
+impl DocumentExists {
+    /// Constructs a new instance of the `DocumentExists` method that will
+    /// check for the existence of the document identified by the given id.
+    pub fn new(id: DocumentId) -> Self {
+        DocumentExists { id }
+    }
+
+    /// Returns the id of the document whose existence is checked.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+}
+
+impl Method for DocumentExists {
+    type Result = Option<Revision>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: None,
+    };
+}
+
+impl Prepare for DocumentExists {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Read
+    }
+
+    fn path(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
+/// Partially updates (HTTP PATCH) a single document identified by its id.
+///
+/// Only the fields present in the update content are merged into the
+/// stored document; fields that are not set remain unchanged. This is in
+/// contrast to `ReplaceDocument`, which overwrites the whole document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateDocument<Upd, Old, New> {
+    id: DocumentId,
+    update: DocumentUpdate<Upd>,
+    ignore_revs: bool,
+    keep_null: bool,
+    merge_objects: bool,
+    if_match: Option<String>,
+    wait_for_sync: bool,
+    return_old: bool,
+    return_new: bool,
+    content_type: PhantomData<(Old, New)>,
+}
+
+impl<Upd, Old, New> UpdateDocument<Upd, Old, New> {
+    /// Constructs a new instance of the `UpdateDocument` method that will
+    /// apply the given update to the document identified by the given id.
+    pub fn new(id: DocumentId, update: DocumentUpdate<Upd>) -> Self {
+        UpdateDocument {
+            id,
+            update,
+            ignore_revs: true,
+            keep_null: true,
+            merge_objects: true,
+            if_match: None,
+            wait_for_sync: false,
+            return_old: false,
+            return_new: false,
+            content_type: PhantomData,
+        }
+    }
+
+    /// Sets whether the revision specified in the update shall be ignored.
+    ///
+    /// If set to `false`, the update is rejected with a precondition failed
+    /// error if the revision does not match the currently stored revision.
+    pub fn with_ignore_revs(mut self, ignore_revs: bool) -> Self {
+        self.ignore_revs = ignore_revs;
+        self
+    }
+
+    /// Sets whether explicit JSON `null` values in the update shall be
+    /// kept, setting the corresponding attribute of the stored document to
+    /// `null`.
+    ///
+    /// If set to `false`, an attribute with a `null` value in the update is
+    /// removed from the stored document instead.
+    pub fn with_keep_null(mut self, keep_null: bool) -> Self {
+        self.keep_null = keep_null;
+        self
+    }
+
+    /// Sets whether nested object attributes in the update shall be merged
+    /// recursively into the stored document rather than overwritten
+    /// wholesale.
+    pub fn with_merge_objects(mut self, merge_objects: bool) -> Self {
+        self.merge_objects = merge_objects;
+        self
+    }
+
+    /// Sets the revision that the stored document must match for the
+    /// update to be applied, using the `If-Match` header.
+    pub fn with_if_match<R>(mut self, revision: R) -> Self
+        where R: Into<String>
+    {
+        self.if_match = Some(revision.into());
+        self
+    }
+
+    /// Sets whether the client shall wait until the update has been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets whether the document as it was before the update shall be
+    /// returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Sets whether the updated document shall be returned in the
+    /// response.
+    pub fn with_return_new(mut self, return_new: bool) -> Self {
+        self.return_new = return_new;
+        self
+    }
+
+    /// Returns the id of the document to be updated.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+
+    /// Returns the update that is going to be applied to the document.
+    pub fn update(&self) -> &DocumentUpdate<Upd> {
+        &self.update
+    }
+}
+
+impl<Upd, Old, New> Method for UpdateDocument<Upd, Old, New>
+    where Old: DeserializeOwned, New: DeserializeOwned
+{
+    type Result = UpdatedDocument<Old, New>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<Upd, Old, New> Prepare for UpdateDocument<Upd, Old, New>
+    where Upd: Serialize
+{
+    type Content = DocumentUpdate<Upd>;
+
+    fn operation(&self) -> Operation {
+        Operation::Modify
+    }
+
+    fn path(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(6);
+        params.insert(PARAM_IGNORE_REVS, self.ignore_revs);
+        params.insert(PARAM_KEEP_NULL, self.keep_null);
+        params.insert(PARAM_MERGE_OBJECTS, self.merge_objects);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        if self.return_new {
+            params.insert(PARAM_RETURN_NEW, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        let mut header = Parameters::empty();
+        if let Some(ref if_match) = self.if_match {
+            header.insert(HEADER_IF_MATCH, if_match.clone());
+        }
+        header
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.update)
+    }
+}
+
+/// Partially updates (HTTP PATCH) a single document identified by its id
+/// and always returns the updated document.
+///
+/// This is a convenience wrapper around `UpdateDocument` that sets
+/// `return_new` to `true`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateDocumentReturnNew<Upd, Old, New> {
+    inner: UpdateDocument<Upd, Old, New>,
+}
+
+impl<Upd, Old, New> UpdateDocumentReturnNew<Upd, Old, New> {
+    /// Constructs a new instance of the `UpdateDocumentReturnNew` method
+    /// that will apply the given update to the document identified by the
+    /// given id.
+    pub fn new(id: DocumentId, update: DocumentUpdate<Upd>) -> Self {
+        UpdateDocumentReturnNew {
+            inner: UpdateDocument::new(id, update).with_return_new(true),
+        }
+    }
+
+    /// Sets whether the document as it was before the update shall also be
+    /// returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.inner = self.inner.with_return_old(return_old);
+        self
+    }
+}
+
+impl<Upd, Old, New> Method for UpdateDocumentReturnNew<Upd, Old, New>
+    where Old: DeserializeOwned, New: DeserializeOwned
+{
+    type Result = UpdatedDocument<Old, New>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<Upd, Old, New> Prepare for UpdateDocumentReturnNew<Upd, Old, New>
+    where Upd: Serialize
+{
+    type Content = DocumentUpdate<Upd>;
+
+    fn operation(&self) -> Operation {
+        self.inner.operation()
+    }
+
+    fn path(&self) -> String {
+        self.inner.path()
+    }
+
+    fn parameters(&self) -> Parameters {
+        self.inner.parameters()
+    }
+
+    fn header(&self) -> Parameters {
+        self.inner.header()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        self.inner.content()
+    }
+}
+
+/// Partially updates (HTTP PATCH) a single document identified by its id.
+///
+/// This is an alias for `UpdateDocument`, kept under the name used by the
+/// ArangoDB HTTP API documentation for this operation, for callers that
+/// prefer that terminology.
+pub type ModifyDocument<Upd, Old, New> = UpdateDocument<Upd, Old, New>;
+
+/// Partially updates (HTTP PATCH) a single document identified by its id
+/// and always returns the updated document.
+///
+/// This is an alias for `UpdateDocumentReturnNew`, kept under the name
+/// used by the ArangoDB HTTP API documentation for this operation.
+pub type ModifyDocumentReturnNew<Upd, Old, New> = UpdateDocumentReturnNew<Upd, Old, New>;
+
+/// Partially updates (HTTP PATCH) multiple documents of a collection in a
+/// single request.
+///
+/// Like `UpdateDocument`, only the fields present in each update are
+/// merged into the corresponding stored document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateDocuments<Upd, Old, New> {
+    collection_name: String,
+    updates: Vec<DocumentUpdate<Upd>>,
+    ignore_revs: bool,
+    keep_null: bool,
+    merge_objects: bool,
+    wait_for_sync: bool,
+    return_old: bool,
+    return_new: bool,
+    content_type: PhantomData<(Old, New)>,
+}
+
+impl<Upd, Old, New> UpdateDocuments<Upd, Old, New> {
+    /// Constructs a new instance of the `UpdateDocuments` method that will
+    /// apply the given updates to documents in the collection with the
+    /// given name.
+    pub fn new<N, Updates>(collection_name: N, updates: Updates) -> Self
+        where N: Into<String>, Updates: IntoIterator<Item=DocumentUpdate<Upd>>
+    {
+        UpdateDocuments {
+            collection_name: collection_name.into(),
+            updates: updates.into_iter().collect(),
+            ignore_revs: true,
+            keep_null: true,
+            merge_objects: true,
+            wait_for_sync: false,
+            return_old: false,
+            return_new: false,
+            content_type: PhantomData,
+        }
+    }
+
+    /// Sets whether the revisions specified in the updates shall be
+    /// ignored.
+    pub fn with_ignore_revs(mut self, ignore_revs: bool) -> Self {
+        self.ignore_revs = ignore_revs;
+        self
+    }
+
+    /// Sets whether explicit JSON `null` values in the updates shall be
+    /// kept, setting the corresponding attributes of the stored documents
+    /// to `null`. If set to `false`, such attributes are removed instead.
+    pub fn with_keep_null(mut self, keep_null: bool) -> Self {
+        self.keep_null = keep_null;
+        self
+    }
+
+    /// Sets whether nested object attributes in the updates shall be
+    /// merged recursively into the stored documents rather than
+    /// overwritten wholesale.
+    pub fn with_merge_objects(mut self, merge_objects: bool) -> Self {
+        self.merge_objects = merge_objects;
+        self
+    }
+
+    /// Sets whether the client shall wait until the updates have been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets whether the documents as they were before the updates shall be
+    /// returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Sets whether the updated documents shall be returned in the
+    /// response.
+    pub fn with_return_new(mut self, return_new: bool) -> Self {
+        self.return_new = return_new;
+        self
+    }
+
+    /// Returns the name of the collection the documents belong to.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the updates that are going to be applied to the documents.
+    pub fn updates(&self) -> &[DocumentUpdate<Upd>] {
+        &self.updates
+    }
+}
+
+impl<Upd, Old, New> Method for UpdateDocuments<Upd, Old, New>
+    where Old: DeserializeOwned, New: DeserializeOwned
+{
+    type Result = Vec<Result<UpdatedDocument<Old, New>, MethodError>>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<Upd, Old, New> Prepare for UpdateDocuments<Upd, Old, New>
+    where Upd: Serialize
+{
+    type Content = Vec<DocumentUpdate<Upd>>;
+
+    fn operation(&self) -> Operation {
+        Operation::Modify
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_DOCUMENT) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(6);
+        params.insert(PARAM_IGNORE_REVS, self.ignore_revs);
+        params.insert(PARAM_KEEP_NULL, self.keep_null);
+        params.insert(PARAM_MERGE_OBJECTS, self.merge_objects);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        if self.return_new {
+            params.insert(PARAM_RETURN_NEW, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.updates)
+    }
+}
+
+/// Partially updates (HTTP PATCH) multiple documents of a collection in a
+/// single request.
+///
+/// This is an alias for `UpdateDocuments`, kept under the name used by the
+/// ArangoDB HTTP API documentation for this operation.
+pub type ModifyDocuments<Upd, Old, New> = UpdateDocuments<Upd, Old, New>;
+
+/// Replaces (HTTP PUT) a single document identified by its id with new
+/// content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaceDocument<Repl> {
+    id: DocumentId,
+    replacement: DocumentUpdate<Repl>,
+    ignore_revs: bool,
+    if_match: Option<String>,
+    wait_for_sync: bool,
+    return_old: bool,
+}
+
+impl<Repl> ReplaceDocument<Repl> {
+    /// Constructs a new instance of the `ReplaceDocument` method that will
+    /// replace the document identified by the given id with the given
+    /// replacement.
+    pub fn new(id: DocumentId, replacement: DocumentUpdate<Repl>) -> Self {
+        ReplaceDocument {
+            id,
+            replacement,
+            ignore_revs: true,
+            if_match: None,
+            wait_for_sync: false,
+            return_old: false,
+        }
+    }
+
+    /// Sets whether the revision specified in the replacement shall be
+    /// ignored.
+    ///
+    /// If set to `false`, the replacement is rejected with a precondition
+    /// failed error if the revision does not match the currently stored
+    /// revision.
+    pub fn with_ignore_revs(mut self, ignore_revs: bool) -> Self {
+        self.ignore_revs = ignore_revs;
+        self
+    }
+
+    /// Sets the revision that the stored document must match for the
+    /// replacement to be applied, using the `If-Match` header.
+    pub fn with_if_match<R>(mut self, revision: R) -> Self
+        where R: Into<String>
+    {
+        self.if_match = Some(revision.into());
+        self
+    }
+
+    /// Sets whether the client shall wait until the replacement has been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets whether the document as it was before the replacement shall be
+    /// returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Returns the id of the document to be replaced.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+
+    /// Returns the replacement that is going to be applied to the document.
+    pub fn replacement(&self) -> &DocumentUpdate<Repl> {
+        &self.replacement
+    }
+}
+
+impl<Repl> Method for ReplaceDocument<Repl> {
+    type Result = DocumentHeader;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<Repl> Prepare for ReplaceDocument<Repl>
+    where Repl: Serialize
+{
+    type Content = DocumentUpdate<Repl>;
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(3);
+        params.insert(PARAM_IGNORE_REVS, self.ignore_revs);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        let mut header = Parameters::empty();
+        if let Some(ref if_match) = self.if_match {
+            header.insert(HEADER_IF_MATCH, if_match.clone());
+        }
+        header
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.replacement)
+    }
+}
+
+/// Replaces (HTTP PUT) multiple documents of a collection in a single
+/// request.
+///
+/// ArangoDB processes the whole array in one round-trip and returns a
+/// parallel array where each element is either a document header or an
+/// embedded error object, so that individual failures (e.g. a revision
+/// conflict on one element) do not prevent the other elements from being
+/// replaced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaceDocuments<Repl> {
+    collection_name: String,
+    updates: Vec<DocumentUpdate<Repl>>,
+    ignore_revs: bool,
+    wait_for_sync: bool,
+    return_old: bool,
+    return_new: bool,
+}
+
+impl<Repl> ReplaceDocuments<Repl> {
+    /// Constructs a new instance of the `ReplaceDocuments` method that will
+    /// replace documents in the collection with the given name.
+    pub fn new<N, Updates>(collection_name: N, updates: Updates) -> Self
+        where N: Into<String>, Updates: IntoIterator<Item=DocumentUpdate<Repl>>
+    {
+        ReplaceDocuments {
+            collection_name: collection_name.into(),
+            updates: updates.into_iter().collect(),
+            ignore_revs: true,
+            wait_for_sync: false,
+            return_old: false,
+            return_new: false,
+        }
+    }
+
+    /// Sets whether the revisions specified in the updates shall be
+    /// ignored.
+    pub fn with_ignore_revs(mut self, ignore_revs: bool) -> Self {
+        self.ignore_revs = ignore_revs;
+        self
+    }
+
+    /// Sets whether the client shall wait until the replacements have been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets whether the documents as they were before the replacement
+    /// shall be returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Sets whether the replaced documents shall be returned in the
+    /// response.
+    pub fn with_return_new(mut self, return_new: bool) -> Self {
+        self.return_new = return_new;
+        self
+    }
+
+    /// Returns the name of the collection the documents belong to.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the replacements that are going to be applied to the
+    /// documents.
+    pub fn updates(&self) -> &[DocumentUpdate<Repl>] {
+        &self.updates
+    }
+}
+
+impl<Repl> Method for ReplaceDocuments<Repl> {
+    type Result = Vec<Result<DocumentHeader, MethodError>>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<Repl> Prepare for ReplaceDocuments<Repl>
+    where Repl: Serialize
+{
+    type Content = Vec<DocumentUpdate<Repl>>;
+
+    fn operation(&self) -> Operation {
+        Operation::Replace
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_DOCUMENT) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(4);
+        params.insert(PARAM_IGNORE_REVS, self.ignore_revs);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        if self.return_new {
+            params.insert(PARAM_RETURN_NEW, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.updates)
+    }
+}
+
+/// Removes (HTTP DELETE) a single document identified by its id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveDocument {
+    id: DocumentId,
+    ignore_revs: bool,
+    if_match: Option<String>,
+    wait_for_sync: bool,
+    return_old: bool,
+}
+
+impl RemoveDocument {
+    /// Constructs a new instance of the `RemoveDocument` method that will
+    /// remove the document identified by the given id.
+    pub fn new(id: DocumentId) -> Self {
+        RemoveDocument {
+            id,
+            ignore_revs: true,
+            if_match: None,
+            wait_for_sync: false,
+            return_old: false,
+        }
+    }
+
+    /// Sets whether the revision specified via `with_if_match` shall be
+    /// ignored.
+    pub fn with_ignore_revs(mut self, ignore_revs: bool) -> Self {
+        self.ignore_revs = ignore_revs;
+        self
+    }
+
+    /// Sets the revision that the stored document must match for the
+    /// removal to be applied, using the `If-Match` header.
+    pub fn with_if_match<R>(mut self, revision: R) -> Self
+        where R: Into<String>
+    {
+        self.if_match = Some(revision.into());
+        self
+    }
+
+    /// Sets whether the client shall wait until the removal has been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets whether the document as it was before the removal shall be
+    /// returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Returns the id of the document to be removed.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+}
+
+impl Method for RemoveDocument {
+    type Result = DocumentHeader;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for RemoveDocument {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Delete
+    }
+
+    fn path(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(2);
+        params.insert(PARAM_IGNORE_REVS, self.ignore_revs);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        let mut header = Parameters::empty();
+        if let Some(ref if_match) = self.if_match {
+            header.insert(HEADER_IF_MATCH, if_match.clone());
+        }
+        header
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
+/// Removes (HTTP DELETE) multiple documents of a collection in a single
+/// request, identified by their keys.
+///
+/// As with `ReplaceDocuments`, the response is a parallel array where each
+/// element is either a document header or an embedded error object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveDocuments {
+    collection_name: String,
+    keys: Vec<DocumentKey>,
+    ignore_revs: bool,
+    wait_for_sync: bool,
+    return_old: bool,
+}
+
+impl RemoveDocuments {
+    /// Constructs a new instance of the `RemoveDocuments` method that will
+    /// remove the documents identified by the given keys from the
+    /// collection with the given name.
+    pub fn new<N, Keys>(collection_name: N, keys: Keys) -> Self
+        where N: Into<String>, Keys: IntoIterator<Item=DocumentKey>
+    {
+        RemoveDocuments {
+            collection_name: collection_name.into(),
+            keys: keys.into_iter().collect(),
+            ignore_revs: true,
+            wait_for_sync: false,
+            return_old: false,
+        }
+    }
+
+    /// Sets whether revisions shall be ignored when removing the
+    /// documents.
+    pub fn with_ignore_revs(mut self, ignore_revs: bool) -> Self {
+        self.ignore_revs = ignore_revs;
+        self
+    }
+
+    /// Sets whether the client shall wait until the removals have been
+    /// written to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Sets whether the documents as they were before the removal shall be
+    /// returned in the response.
+    pub fn with_return_old(mut self, return_old: bool) -> Self {
+        self.return_old = return_old;
+        self
+    }
+
+    /// Returns the name of the collection the documents belong to.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the keys of the documents that are going to be removed.
+    pub fn keys(&self) -> &[DocumentKey] {
+        &self.keys
+    }
+}
+
+impl Method for RemoveDocuments {
+    type Result = Vec<Result<DocumentHeader, MethodError>>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for RemoveDocuments {
+    type Content = Vec<DocumentKey>;
+
+    fn operation(&self) -> Operation {
+        Operation::Delete
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_DOCUMENT) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(3);
+        params.insert(PARAM_IGNORE_REVS, self.ignore_revs);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        if self.return_old {
+            params.insert(PARAM_RETURN_OLD, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.keys)
+    }
+}
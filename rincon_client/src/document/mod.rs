@@ -0,0 +1,11 @@
+//! Types and methods for working with documents.
+
+pub mod codec;
+pub mod cursor;
+pub mod error;
+pub mod import;
+pub mod methods;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,58 @@
+use std::io::Cursor;
+
+use super::import::NdjsonImportBatches;
+use super::types::OverwriteMode;
+
+#[test]
+fn overwrite_mode_as_str_maps_to_server_values() {
+    assert_eq!("conflict", OverwriteMode::Conflict.as_str());
+    assert_eq!("ignore", OverwriteMode::Ignore.as_str());
+    assert_eq!("replace", OverwriteMode::Replace.as_str());
+    assert_eq!("update", OverwriteMode::Update.as_str());
+}
+
+#[test]
+fn next_batch_splits_input_into_bounded_batches() {
+    let reader = Cursor::new(b"{\"a\":1}\n{\"a\":2}\n{\"a\":3}\n".to_vec());
+    let mut batches = NdjsonImportBatches::new("my_collection", reader)
+        .with_batch_size(2);
+
+    let first = batches.next_batch().unwrap().unwrap();
+    assert_eq!(2, first.len());
+
+    let second = batches.next_batch().unwrap().unwrap();
+    assert_eq!(1, second.len());
+
+    assert_eq!(None, batches.next_batch().unwrap());
+}
+
+#[test]
+fn next_batch_skips_blank_lines() {
+    let reader = Cursor::new(b"{\"a\":1}\n\n{\"a\":2}\n".to_vec());
+    let mut batches = NdjsonImportBatches::new("my_collection", reader)
+        .with_batch_size(10);
+
+    let batch = batches.next_batch().unwrap().unwrap();
+    assert_eq!(2, batch.len());
+}
+
+#[test]
+fn next_batch_yields_final_partial_batch_without_trailing_newline() {
+    let reader = Cursor::new(b"{\"a\":1}\n{\"a\":2}".to_vec());
+    let mut batches = NdjsonImportBatches::new("my_collection", reader)
+        .with_batch_size(10);
+
+    let batch = batches.next_batch().unwrap().unwrap();
+    assert_eq!(2, batch.len());
+
+    assert_eq!(None, batches.next_batch().unwrap());
+}
+
+#[test]
+fn next_batch_returns_none_for_empty_input() {
+    let reader = Cursor::new(Vec::new());
+    let mut batches = NdjsonImportBatches::new("my_collection", reader)
+        .with_batch_size(10);
+
+    assert_eq!(None, batches.next_batch().unwrap());
+}
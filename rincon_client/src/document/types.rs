@@ -0,0 +1,251 @@
+use rincon_core::api::ErrorCode;
+
+/// Controls how the server reacts when `InsertDocument`/`InsertDocuments`
+/// insert a document whose `_key` already exists in the target collection,
+/// instead of failing with `ErrorCode::ArangoUniqueConstraintViolated`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverwriteMode {
+    /// Fail with a unique constraint violation, as if no overwrite mode
+    /// had been set. This is the default.
+    Conflict,
+    /// Keep the existing document unchanged and report success without
+    /// applying any change.
+    Ignore,
+    /// Fully replace the stored document with the new one.
+    Replace,
+    /// Merge the new document into the stored one like a partial update,
+    /// honoring `keep_null`/`merge_objects`.
+    Update,
+}
+
+impl OverwriteMode {
+    pub(super) fn as_str(&self) -> &'static str {
+        match *self {
+            OverwriteMode::Conflict => "conflict",
+            OverwriteMode::Ignore => "ignore",
+            OverwriteMode::Replace => "replace",
+            OverwriteMode::Update => "update",
+        }
+    }
+}
+
+/// The result of inserting a single document via `InsertDocument`, or of
+/// an individual element of `InsertDocuments`.
+///
+/// This mirrors the plain document header but additionally reports
+/// whether an existing document was overwritten, as configured via
+/// `with_overwrite_mode`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InsertedDocument<Old> {
+    #[serde(rename = "_id")]
+    id: DocumentId,
+    #[serde(rename = "_key")]
+    key: DocumentKey,
+    #[serde(rename = "_rev")]
+    revision: Revision,
+    #[serde(rename = "_oldRev")]
+    old_revision: Option<Revision>,
+    #[serde(default)]
+    old: Option<Old>,
+}
+
+impl<Old> InsertedDocument<Old> {
+    /// Returns the id of the inserted document.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+
+    /// Returns the key of the inserted document.
+    pub fn key(&self) -> &DocumentKey {
+        &self.key
+    }
+
+    /// Returns the revision of the inserted document.
+    pub fn revision(&self) -> &Revision {
+        &self.revision
+    }
+
+    /// Returns the revision the document had before being overwritten, if
+    /// an existing document with the same `_key` was overwritten.
+    pub fn old_revision(&self) -> Option<&Revision> {
+        self.old_revision.as_ref()
+    }
+
+    /// Returns whether this insert overwrote an already existing document,
+    /// as configured via `with_overwrite_mode`.
+    pub fn is_overwritten(&self) -> bool {
+        self.old_revision.is_some()
+    }
+
+    /// Returns the content the overwritten document had before being
+    /// overwritten, if it was overwritten and the request was made with
+    /// `with_return_old(true)`.
+    pub fn old_content(&self) -> Option<&Old> {
+        self.old.as_ref()
+    }
+
+    /// Consumes this result and returns its id, key and revision.
+    pub fn deconstruct(self) -> (DocumentId, DocumentKey, Revision) {
+        (self.id, self.key, self.revision)
+    }
+}
+
+/// The result of inserting a single document via `InsertDocumentReturnNew`,
+/// or of an individual element of `InsertDocumentsReturnNew`.
+///
+/// This is like `InsertedDocument`, but additionally carries the content
+/// of the inserted document, mirroring `Document::content`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InsertedDocumentReturnNew<Old, New> {
+    #[serde(rename = "_id")]
+    id: DocumentId,
+    #[serde(rename = "_key")]
+    key: DocumentKey,
+    #[serde(rename = "_rev")]
+    revision: Revision,
+    #[serde(rename = "_oldRev")]
+    old_revision: Option<Revision>,
+    #[serde(default)]
+    old: Option<Old>,
+    new: New,
+}
+
+impl<Old, New> InsertedDocumentReturnNew<Old, New> {
+    /// Returns the id of the inserted document.
+    pub fn id(&self) -> &DocumentId {
+        &self.id
+    }
+
+    /// Returns the key of the inserted document.
+    pub fn key(&self) -> &DocumentKey {
+        &self.key
+    }
+
+    /// Returns the revision of the inserted document.
+    pub fn revision(&self) -> &Revision {
+        &self.revision
+    }
+
+    /// Returns the revision the document had before being overwritten, if
+    /// an existing document with the same `_key` was overwritten.
+    pub fn old_revision(&self) -> Option<&Revision> {
+        self.old_revision.as_ref()
+    }
+
+    /// Returns whether this insert overwrote an already existing document,
+    /// as configured via `with_overwrite_mode`.
+    pub fn is_overwritten(&self) -> bool {
+        self.old_revision.is_some()
+    }
+
+    /// Returns the content the overwritten document had before being
+    /// overwritten, if it was overwritten and the request was made with
+    /// `with_return_old(true)`.
+    pub fn old_content(&self) -> Option<&Old> {
+        self.old.as_ref()
+    }
+
+    /// Returns the content of the inserted document.
+    pub fn content(&self) -> &New {
+        &self.new
+    }
+}
+
+/// A summary of a bulk import of documents, as returned by the
+/// `ImportDocumentBatch` method and accumulated across batches with
+/// `ImportSummary::merge`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportSummary {
+    created: u64,
+    errors: u64,
+    empty: u64,
+    updated: u64,
+    ignored: u64,
+    #[serde(default)]
+    details: Vec<ImportError>,
+}
+
+impl ImportSummary {
+    /// Constructs an `ImportSummary` with all counters at zero.
+    ///
+    /// This is the neutral element used to accumulate the summaries of the
+    /// individual batches sent to the server into one overall summary.
+    pub fn empty() -> Self {
+        ImportSummary {
+            created: 0,
+            errors: 0,
+            empty: 0,
+            updated: 0,
+            ignored: 0,
+            details: Vec::new(),
+        }
+    }
+
+    /// Returns the number of documents that have been created.
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /// Returns the number of documents that could not be imported due to an
+    /// error.
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// Returns the number of empty lines that have been skipped.
+    pub fn empty(&self) -> u64 {
+        self.empty
+    }
+
+    /// Returns the number of documents that have been updated or replaced,
+    /// depending on the `on_duplicate` mode used for the import.
+    pub fn updated(&self) -> u64 {
+        self.updated
+    }
+
+    /// Returns the number of documents that have been ignored because a
+    /// document with the same `_key` already existed.
+    pub fn ignored(&self) -> u64 {
+        self.ignored
+    }
+
+    /// Returns the per-line error details collected for this import, if
+    /// the import was executed with details enabled.
+    pub fn details(&self) -> &[ImportError] {
+        &self.details
+    }
+
+    /// Combines this summary with the summary of another batch, adding up
+    /// all counters and details.
+    pub fn merge(mut self, other: ImportSummary) -> Self {
+        self.created += other.created;
+        self.errors += other.errors;
+        self.empty += other.empty;
+        self.updated += other.updated;
+        self.ignored += other.ignored;
+        self.details.extend(other.details);
+        self
+    }
+}
+
+/// A single line-level failure encountered while importing documents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportError {
+    #[serde(rename = "errorNum")]
+    code: ErrorCode,
+    #[serde(rename = "errorMessage")]
+    message: String,
+}
+
+impl ImportError {
+    /// Returns the `ErrorCode` reported by the server for this line.
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    /// Returns the human-readable error message reported by the server for
+    /// this line.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
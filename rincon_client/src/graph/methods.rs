@@ -0,0 +1,277 @@
+//! Methods for working with edge documents and graph traversals.
+
+use std::marker::PhantomData;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use rincon_core::api::method::{Method, Operation, Parameters, Prepare, RpcReturnType};
+use rincon_core::arango::protocol::{FIELD_CODE, FIELD_EDGES, FIELD_RESULT, PARAM_DIRECTION,
+    PARAM_VERTEX, PARAM_WAIT_FOR_SYNC, PATH_API_DOCUMENT, PATH_API_EDGES, PATH_API_TRAVERSAL};
+use document::methods::{RemoveDocument, ReplaceDocument, UpdateDocument};
+use document::types::{DocumentId, InsertedDocument};
+use super::types::*;
+
+/// Creates (HTTP POST) a single new edge document in an edge collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertEdge<T> {
+    collection_name: String,
+    edge: NewEdge<T>,
+    wait_for_sync: bool,
+}
+
+impl<T> InsertEdge<T> {
+    /// Constructs a new instance of the `InsertEdge` method that will
+    /// insert the given edge into the collection with the given name.
+    pub fn new<N>(collection_name: N, edge: NewEdge<T>) -> Self
+        where N: Into<String>
+    {
+        InsertEdge {
+            collection_name: collection_name.into(),
+            edge,
+            wait_for_sync: false,
+        }
+    }
+
+    /// Sets whether the client shall wait until the edge has been written
+    /// to disk before the response is returned.
+    pub fn with_force_wait_for_sync(mut self, wait_for_sync: bool) -> Self {
+        self.wait_for_sync = wait_for_sync;
+        self
+    }
+
+    /// Returns the name of the collection the edge is inserted into.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the edge that is going to be inserted.
+    pub fn edge(&self) -> &NewEdge<T> {
+        &self.edge
+    }
+}
+
+impl<T> Method for InsertEdge<T>
+    where T: DeserializeOwned
+{
+    type Result = InsertedDocument<T>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<T> Prepare for InsertEdge<T>
+    where T: Serialize
+{
+    type Content = NewEdge<T>;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_DOCUMENT) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(1);
+        if self.wait_for_sync {
+            params.insert(PARAM_WAIT_FOR_SYNC, true);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.edge)
+    }
+}
+
+/// Partially updates (HTTP PATCH) a single edge document.
+///
+/// Once created, an edge is updated the same way as any other document,
+/// so this is simply an alias for `UpdateDocument`.
+pub type UpdateEdge<Upd, Old, New> = UpdateDocument<Upd, Old, New>;
+
+/// Replaces (HTTP PUT) a single edge document.
+///
+/// Once created, an edge is replaced the same way as any other document,
+/// so this is simply an alias for `ReplaceDocument`.
+pub type ReplaceEdge<Repl> = ReplaceDocument<Repl>;
+
+/// Removes (HTTP DELETE) a single edge document.
+///
+/// Once created, an edge is removed the same way as any other document,
+/// so this is simply an alias for `RemoveDocument`.
+pub type RemoveEdge = RemoveDocument;
+
+/// Looks up (HTTP GET) the edges directly connected to a start vertex.
+///
+/// This is the direct edge lookup; `Traverse` is the more general method
+/// that walks the graph to an arbitrary depth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetEdges<E> {
+    collection_name: String,
+    vertex: DocumentId,
+    direction: Option<Direction>,
+    edge_type: PhantomData<E>,
+}
+
+impl<E> GetEdges<E> {
+    /// Constructs a new instance of the `GetEdges` method that will look
+    /// up the edges of the collection with the given name that are
+    /// connected to the given start vertex.
+    pub fn new<N>(collection_name: N, vertex: DocumentId) -> Self
+        where N: Into<String>
+    {
+        GetEdges {
+            collection_name: collection_name.into(),
+            vertex,
+            direction: None,
+            edge_type: PhantomData,
+        }
+    }
+
+    /// Restricts the lookup to edges pointing in the given direction
+    /// relative to the start vertex.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+}
+
+impl<E> Method for GetEdges<E>
+    where E: DeserializeOwned
+{
+    type Result = Vec<E>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: Some(FIELD_EDGES),
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<E> Prepare for GetEdges<E> {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Read
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_EDGES) + "/" + &self.collection_name
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(2);
+        params.insert(PARAM_VERTEX, self.vertex.to_string());
+        if let Some(direction) = self.direction {
+            let direction = match direction {
+                Direction::Any => "any",
+                Direction::Inbound => "inbound",
+                Direction::Outbound => "outbound",
+            };
+            params.insert(PARAM_DIRECTION, direction);
+        }
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
+
+/// Walks the graph reachable from a start vertex along the edges of a
+/// given edge collection.
+///
+/// Vertices and edges visited during the traversal are deserialized into
+/// `V` and `E` respectively, the same way `InsertDocumentsReturnNew`
+/// returns typed content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Traverse<V, E> {
+    query: TraversalQuery,
+    vertex_type: PhantomData<V>,
+    edge_type: PhantomData<E>,
+}
+
+impl<V, E> Traverse<V, E> {
+    /// Constructs a new instance of the `Traverse` method that will walk
+    /// the graph reachable from the given start vertex, following edges
+    /// of the given edge collection outwards.
+    pub fn new<N>(edge_collection_name: N, start_vertex: DocumentId) -> Self
+        where N: Into<String>
+    {
+        Traverse {
+            query: TraversalQuery::new(edge_collection_name, start_vertex),
+            vertex_type: PhantomData,
+            edge_type: PhantomData,
+        }
+    }
+
+    /// Sets the direction in which edges are followed.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.query = self.query.with_direction(direction);
+        self
+    }
+
+    /// Sets the minimum path length a vertex must have from the start
+    /// vertex to be included in the result.
+    pub fn with_min_depth(mut self, min_depth: u32) -> Self {
+        self.query = self.query.with_min_depth(min_depth);
+        self
+    }
+
+    /// Sets the maximum path length that is followed from the start
+    /// vertex.
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.query = self.query.with_max_depth(max_depth);
+        self
+    }
+
+    /// Sets the uniqueness policy applied to visited vertices and edges.
+    pub fn with_uniqueness(mut self, uniqueness: Uniqueness) -> Self {
+        self.query = self.query.with_uniqueness(uniqueness);
+        self
+    }
+}
+
+impl<V, E> Method for Traverse<V, E>
+    where V: DeserializeOwned, E: DeserializeOwned
+{
+    type Result = TraversalResult<V, E>;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: Some(FIELD_RESULT),
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl<V, E> Prepare for Traverse<V, E> {
+    type Content = TraversalQuery;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_TRAVERSAL)
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.query)
+    }
+}
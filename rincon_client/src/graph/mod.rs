@@ -0,0 +1,5 @@
+//! Types and methods for working with edge documents and graph
+//! traversals.
+
+pub mod methods;
+pub mod types;
@@ -0,0 +1,227 @@
+use document::types::{DocumentId, DocumentKey};
+
+/// A new edge document to be inserted via `InsertEdge`.
+///
+/// Unlike a plain `NewDocument`, this always carries the mandatory `_from`
+/// and `_to` endpoints of an edge. Since both are typed as `DocumentId`
+/// rather than bare strings, a malformed endpoint is rejected by the type
+/// system at the call site instead of only being caught by the server.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NewEdge<T> {
+    #[serde(rename = "_from")]
+    from: DocumentId,
+    #[serde(rename = "_to")]
+    to: DocumentId,
+    #[serde(rename = "_key", skip_serializing_if = "Option::is_none")]
+    key: Option<DocumentKey>,
+    #[serde(flatten)]
+    content: T,
+}
+
+impl<T> NewEdge<T> {
+    /// Constructs a new edge from `from` to `to`, carrying the given
+    /// content.
+    pub fn new(from: DocumentId, to: DocumentId, content: T) -> Self {
+        NewEdge {
+            from,
+            to,
+            key: None,
+            content,
+        }
+    }
+
+    /// Sets the `_key` the edge is stored under, instead of letting the
+    /// server generate one.
+    pub fn with_key(mut self, key: DocumentKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Returns the start vertex of this edge.
+    pub fn from(&self) -> &DocumentId {
+        &self.from
+    }
+
+    /// Returns the end vertex of this edge.
+    pub fn to(&self) -> &DocumentId {
+        &self.to
+    }
+
+    /// Returns the content of this edge.
+    pub fn content(&self) -> &T {
+        &self.content
+    }
+}
+
+/// The direction in which edges are followed from a start vertex, by
+/// `Traverse` and `GetEdges`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// Follow both inbound and outbound edges.
+    Any,
+    /// Follow only edges pointing towards the current vertex.
+    Inbound,
+    /// Follow only edges pointing away from the current vertex.
+    Outbound,
+}
+
+/// How repeated visits of the same vertex or edge are handled by
+/// `Traverse`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UniquenessPolicy {
+    /// Allow a vertex or edge to be visited any number of times.
+    None,
+    /// Allow a vertex or edge to be visited again as long as it has not
+    /// already been visited on the current path.
+    Path,
+    /// Never visit the same vertex or edge more than once across the
+    /// whole traversal.
+    Global,
+}
+
+/// The uniqueness policy applied by `Traverse`, separately for visited
+/// vertices and edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub struct Uniqueness {
+    vertices: UniquenessPolicy,
+    edges: UniquenessPolicy,
+}
+
+impl Uniqueness {
+    /// Constructs a `Uniqueness` policy with the given policy applied to
+    /// visited vertices and edges respectively.
+    pub fn new(vertices: UniquenessPolicy, edges: UniquenessPolicy) -> Self {
+        Uniqueness { vertices, edges }
+    }
+
+    /// Returns the uniqueness policy applied to visited vertices.
+    pub fn vertices(&self) -> UniquenessPolicy {
+        self.vertices
+    }
+
+    /// Returns the uniqueness policy applied to visited edges.
+    pub fn edges(&self) -> UniquenessPolicy {
+        self.edges
+    }
+}
+
+impl Default for Uniqueness {
+    /// The server's own default: neither vertices nor edges may be
+    /// revisited on the same path.
+    fn default() -> Self {
+        Uniqueness::new(UniquenessPolicy::Path, UniquenessPolicy::Path)
+    }
+}
+
+/// One path followed during a traversal, from the start vertex up to one
+/// of the vertices visited along the way.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Path<V, E> {
+    vertices: Vec<V>,
+    edges: Vec<E>,
+}
+
+impl<V, E> Path<V, E> {
+    /// Returns the vertices on this path, starting with the start vertex.
+    pub fn vertices(&self) -> &[V] {
+        &self.vertices
+    }
+
+    /// Returns the edges on this path, in the order they were followed.
+    pub fn edges(&self) -> &[E] {
+        &self.edges
+    }
+}
+
+/// The vertices and paths visited during a traversal.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Visited<V, E> {
+    vertices: Vec<V>,
+    paths: Vec<Path<V, E>>,
+}
+
+impl<V, E> Visited<V, E> {
+    /// Returns the distinct vertices visited during the traversal.
+    pub fn vertices(&self) -> &[V] {
+        &self.vertices
+    }
+
+    /// Returns every path followed during the traversal.
+    pub fn paths(&self) -> &[Path<V, E>] {
+        &self.paths
+    }
+}
+
+/// The result of a `Traverse` method, deserializing vertices as `V` and
+/// edges as `E`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TraversalResult<V, E> {
+    visited: Visited<V, E>,
+}
+
+impl<V, E> TraversalResult<V, E> {
+    /// Returns the vertices and paths visited during the traversal.
+    pub fn visited(&self) -> &Visited<V, E> {
+        &self.visited
+    }
+}
+
+/// The body of a `Traverse` request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TraversalQuery {
+    #[serde(rename = "startVertex")]
+    start_vertex: DocumentId,
+    #[serde(rename = "edgeCollection")]
+    edge_collection: String,
+    direction: Direction,
+    #[serde(rename = "minDepth", skip_serializing_if = "Option::is_none")]
+    min_depth: Option<u32>,
+    #[serde(rename = "maxDepth", skip_serializing_if = "Option::is_none")]
+    max_depth: Option<u32>,
+    uniqueness: Uniqueness,
+}
+
+impl TraversalQuery {
+    /// Constructs a new `TraversalQuery` that walks the given edge
+    /// collection outwards from the given start vertex.
+    pub fn new<N>(edge_collection: N, start_vertex: DocumentId) -> Self
+        where N: Into<String>
+    {
+        TraversalQuery {
+            start_vertex,
+            edge_collection: edge_collection.into(),
+            direction: Direction::Outbound,
+            min_depth: None,
+            max_depth: None,
+            uniqueness: Uniqueness::default(),
+        }
+    }
+
+    /// Sets the direction in which edges are followed.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the minimum path length a vertex must have from the start
+    /// vertex to be included in the result.
+    pub fn with_min_depth(mut self, min_depth: u32) -> Self {
+        self.min_depth = Some(min_depth);
+        self
+    }
+
+    /// Sets the maximum path length that is followed from the start
+    /// vertex.
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the uniqueness policy applied to visited vertices and edges.
+    pub fn with_uniqueness(mut self, uniqueness: Uniqueness) -> Self {
+        self.uniqueness = uniqueness;
+        self
+    }
+}
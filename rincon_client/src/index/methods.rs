@@ -0,0 +1,129 @@
+//! Methods for managing indexes.
+
+use rincon_core::api::method::{Method, Operation, Parameters, Prepare, RpcReturnType};
+use rincon_core::arango::protocol::{FIELD_CODE, PARAM_COLLECTION, PATH_API_INDEX};
+use super::types::*;
+
+/// Creates (HTTP POST) a new index on a collection.
+///
+/// If an index with the same definition already exists, that index is
+/// returned instead of creating a duplicate; `Index::is_newly_created` on
+/// the result distinguishes the two cases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateIndex {
+    collection_name: String,
+    index: NewIndex,
+}
+
+impl CreateIndex {
+    /// Constructs a new instance of the `CreateIndex` method that will
+    /// create the given index on the collection with the given name.
+    pub fn new<N>(collection_name: N, index: NewIndex) -> Self
+        where N: Into<String>
+    {
+        CreateIndex {
+            collection_name: collection_name.into(),
+            index,
+        }
+    }
+
+    /// Returns the name of the collection the index is created on.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the parameters of the index that is going to be created.
+    pub fn index(&self) -> &NewIndex {
+        &self.index
+    }
+}
+
+impl Method for CreateIndex {
+    type Result = Index;
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for CreateIndex {
+    type Content = NewIndex;
+
+    fn operation(&self) -> Operation {
+        Operation::Create
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_INDEX)
+    }
+
+    fn parameters(&self) -> Parameters {
+        let mut params = Parameters::with_capacity(1);
+        params.insert(PARAM_COLLECTION, self.collection_name.clone());
+        params
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        Some(&self.index)
+    }
+}
+
+/// Deletes (HTTP DELETE) an index of a collection, identified by its id in
+/// the form `collection/key`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteIndex {
+    index_id: String,
+}
+
+impl DeleteIndex {
+    /// Constructs a new instance of the `DeleteIndex` method that will
+    /// delete the index with the given id.
+    pub fn new<Id>(index_id: Id) -> Self
+        where Id: Into<String>
+    {
+        DeleteIndex {
+            index_id: index_id.into(),
+        }
+    }
+
+    /// Returns the id of the index that is going to be deleted.
+    pub fn index_id(&self) -> &str {
+        &self.index_id
+    }
+}
+
+impl Method for DeleteIndex {
+    type Result = ();
+    const RETURN_TYPE: RpcReturnType = RpcReturnType {
+        result_field: None,
+        code_field: Some(FIELD_CODE),
+    };
+}
+
+impl Prepare for DeleteIndex {
+    type Content = ();
+
+    fn operation(&self) -> Operation {
+        Operation::Delete
+    }
+
+    fn path(&self) -> String {
+        String::from(PATH_API_INDEX) + "/" + &self.index_id
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn header(&self) -> Parameters {
+        Parameters::empty()
+    }
+
+    fn content(&self) -> Option<&Self::Content> {
+        None
+    }
+}
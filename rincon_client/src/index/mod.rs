@@ -0,0 +1,4 @@
+//! Types and methods for managing indexes of ArangoDB collections.
+
+pub mod methods;
+pub mod types;
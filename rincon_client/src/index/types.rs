@@ -0,0 +1,258 @@
+/// The kind of an index, determining which of `NewIndex`'s kind-specific
+/// options apply and how the index behaves.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndexKind {
+    /// The automatically created index on `_key`. Never created by hand.
+    Primary,
+    /// The automatically created index on `_from`/`_to` of an edge
+    /// collection. Never created by hand.
+    Edge,
+    /// A hash index over one or more fields.
+    Hash,
+    /// A sorted index over one or more fields, also usable for range
+    /// queries.
+    Skiplist,
+    /// Functionally equivalent to `Skiplist`, backed by a persistent data
+    /// structure rather than an in-memory one.
+    Persistent,
+    /// A geospatial index over a field holding coordinates or a GeoJSON
+    /// object.
+    Geo,
+    /// A full-text index over the words of a string field.
+    Fulltext,
+    /// An index that automatically removes documents once their indexed
+    /// timestamp field is older than `expire_after` seconds.
+    Ttl,
+}
+
+/// The parameters used to create a new index via `CreateIndex`.
+///
+/// Construct one via the kind-specific constructor (`hash`, `skiplist`,
+/// `persistent`, `geo`, `fulltext` or `ttl`) and then apply whichever of
+/// the kind-specific options are relevant, e.g.
+/// `NewIndex::hash(vec!["email"]).with_unique(true)`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NewIndex {
+    #[serde(rename = "type")]
+    kind: IndexKind,
+    fields: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unique: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sparse: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deduplicate: Option<bool>,
+    #[serde(rename = "geoJson", skip_serializing_if = "Option::is_none")]
+    geo_json: Option<bool>,
+    #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+    min_length: Option<u32>,
+    #[serde(rename = "expireAfter", skip_serializing_if = "Option::is_none")]
+    expire_after: Option<u32>,
+}
+
+impl NewIndex {
+    fn with_kind<Fields>(kind: IndexKind, fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex {
+            kind,
+            fields: fields.into_iter().map(Into::into).collect(),
+            unique: None,
+            sparse: None,
+            deduplicate: None,
+            geo_json: None,
+            min_length: None,
+            expire_after: None,
+        }
+    }
+
+    /// Constructs the parameters for a new hash index over the given
+    /// fields.
+    pub fn hash<Fields>(fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex::with_kind(IndexKind::Hash, fields)
+    }
+
+    /// Constructs the parameters for a new skiplist index over the given
+    /// fields.
+    pub fn skiplist<Fields>(fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex::with_kind(IndexKind::Skiplist, fields)
+    }
+
+    /// Constructs the parameters for a new persistent index over the
+    /// given fields.
+    pub fn persistent<Fields>(fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex::with_kind(IndexKind::Persistent, fields)
+    }
+
+    /// Constructs the parameters for a new geo index over the given
+    /// field(s).
+    pub fn geo<Fields>(fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex::with_kind(IndexKind::Geo, fields)
+    }
+
+    /// Constructs the parameters for a new full-text index over the given
+    /// field.
+    pub fn fulltext<Fields>(fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex::with_kind(IndexKind::Fulltext, fields)
+    }
+
+    /// Constructs the parameters for a new TTL index over the given
+    /// field.
+    pub fn ttl<Fields>(fields: Fields) -> Self
+        where Fields: IntoIterator, Fields::Item: Into<String>
+    {
+        NewIndex::with_kind(IndexKind::Ttl, fields)
+    }
+
+    /// Sets whether the indexed field values must be unique across all
+    /// documents. Applies to `hash`, `skiplist` and `persistent` indexes.
+    pub fn with_unique(mut self, unique: bool) -> Self {
+        self.unique = Some(unique);
+        self
+    }
+
+    /// Sets whether documents that do not contain the indexed field(s)
+    /// are excluded from the index. Applies to `hash`, `skiplist` and
+    /// `persistent` indexes.
+    pub fn with_sparse(mut self, sparse: bool) -> Self {
+        self.sparse = Some(sparse);
+        self
+    }
+
+    /// Sets whether duplicate array values are deduplicated before being
+    /// indexed. Applies to `hash`, `skiplist` and `persistent` indexes on
+    /// array fields.
+    pub fn with_deduplicate(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = Some(deduplicate);
+        self
+    }
+
+    /// Sets whether the indexed field holds a GeoJSON object rather than
+    /// a `[latitude, longitude]` array. Applies to `geo` indexes.
+    pub fn with_geo_json(mut self, geo_json: bool) -> Self {
+        self.geo_json = Some(geo_json);
+        self
+    }
+
+    /// Sets the minimum character length of substrings that are indexed.
+    /// Applies to `fulltext` indexes.
+    pub fn with_min_length(mut self, min_length: u32) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Sets the number of seconds after the indexed timestamp at which a
+    /// document expires and is removed. Applies to `ttl` indexes.
+    pub fn with_expire_after(mut self, expire_after: u32) -> Self {
+        self.expire_after = Some(expire_after);
+        self
+    }
+
+    /// Returns the kind of index that is going to be created.
+    pub fn kind(&self) -> IndexKind {
+        self.kind
+    }
+
+    /// Returns the fields the index is going to be created over.
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+}
+
+/// The properties common to every index, regardless of its kind.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IndexDetails {
+    id: String,
+    fields: Vec<String>,
+    #[serde(default)]
+    unique: bool,
+    #[serde(default)]
+    sparse: bool,
+    #[serde(default)]
+    deduplicate: bool,
+    #[serde(rename = "geoJson", default)]
+    geo_json: bool,
+    #[serde(rename = "minLength")]
+    min_length: Option<u32>,
+    #[serde(rename = "expireAfter")]
+    expire_after: Option<u32>,
+    #[serde(rename = "isNewlyCreated")]
+    is_newly_created: Option<bool>,
+    #[serde(rename = "selectivityEstimate")]
+    selectivity_estimate: Option<f64>,
+}
+
+/// An index of a collection, as returned by `CreateIndex` or a future
+/// index-listing method.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Index {
+    /// The automatically created index on `_key`.
+    Primary(IndexDetails),
+    /// The automatically created index on `_from`/`_to` of an edge
+    /// collection.
+    Edge(IndexDetails),
+    /// A hash index.
+    Hash(IndexDetails),
+    /// A skiplist index.
+    Skiplist(IndexDetails),
+    /// A persistent index.
+    Persistent(IndexDetails),
+    /// A geo index.
+    Geo(IndexDetails),
+    /// A full-text index.
+    Fulltext(IndexDetails),
+    /// A TTL index.
+    Ttl(IndexDetails),
+}
+
+impl Index {
+    fn details(&self) -> &IndexDetails {
+        match *self {
+            Index::Primary(ref details) |
+            Index::Edge(ref details) |
+            Index::Hash(ref details) |
+            Index::Skiplist(ref details) |
+            Index::Persistent(ref details) |
+            Index::Geo(ref details) |
+            Index::Fulltext(ref details) |
+            Index::Ttl(ref details) => details,
+        }
+    }
+
+    /// Returns the id of this index, in the form `collection/key`.
+    pub fn id(&self) -> &str {
+        &self.details().id
+    }
+
+    /// Returns the fields this index is built over.
+    pub fn fields(&self) -> &[String] {
+        &self.details().fields
+    }
+
+    /// Returns whether this index was just created by the request that
+    /// returned it, as opposed to an already existing index that matched
+    /// the requested definition.
+    ///
+    /// `None` for indexes obtained other than through `CreateIndex`.
+    pub fn is_newly_created(&self) -> Option<bool> {
+        self.details().is_newly_created
+    }
+
+    /// Returns the selectivity estimate of this index, if the server
+    /// reported one for this index kind.
+    pub fn selectivity_estimate(&self) -> Option<f64> {
+        self.details().selectivity_estimate
+    }
+}
@@ -14,6 +14,7 @@ use arangodb_client::api::ErrorCode;
 use arangodb_client::api::types::JsonString;
 use arangodb_client::connection::Error;
 use arangodb_client::document::*;
+use arangodb_client::document::error::{DocumentError, MethodError};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 struct Customer {
@@ -503,7 +504,8 @@ fn get_document_as_struct_inserted_as_struct() {
         let (document_id, document_key, revision) = header.deconstruct();
 
         let method = GetDocument::new(document_id.clone());
-        let document = core.run(conn.execute(method)).unwrap();
+        let document = core.run(conn.execute(method)).unwrap()
+            .expect("document should not have been modified");
 
         assert_eq!("customers10", document.id().collection_name());
         assert_eq!(&document_id, document.id());
@@ -553,7 +555,8 @@ fn get_document_as_struct_inserted_as_json_string() {
         let (document_id, document_key, revision) = header.deconstruct();
 
         let method = GetDocument::new(document_id.clone());
-        let document = core.run(conn.execute(method)).unwrap();
+        let document = core.run(conn.execute(method)).unwrap()
+            .expect("document should not have been modified");
 
         assert_eq!("customers11", document.id().collection_name());
         assert_eq!(&document_id, document.id());
@@ -589,7 +592,8 @@ fn get_document_as_json_string_inserted_as_struct() {
         let (document_id, document_key, revision) = header.deconstruct();
 
         let method = GetDocument::new(document_id.clone());
-        let document: Document<JsonString> = core.run(conn.execute(method)).unwrap();
+        let document: Document<JsonString> = core.run(conn.execute(method)).unwrap()
+            .expect("document should not have been modified");
 
         assert_eq!("customers12", document.id().collection_name());
         assert_eq!(&document_id, document.id());
@@ -625,7 +629,8 @@ fn get_document_if_revision_matches() {
 
         let method = GetDocument::new(document_id.clone())
             .with_if_match(revision.as_str().to_owned());
-        let document = core.run(conn.execute(method)).unwrap();
+        let document = core.run(conn.execute(method)).unwrap()
+            .expect("document should not have been modified");
 
         assert_eq!("customers13", document.id().collection_name());
         assert_eq!(&document_id, document.id());
@@ -635,6 +640,37 @@ fn get_document_if_revision_matches() {
     });
 }
 
+#[test]
+fn get_document_not_modified_if_revision_still_matches() {
+    arango_test_with_document_collection("customers135", |conn, ref mut core| {
+
+        let customer = Customer {
+            name: "Jane Doe".to_owned(),
+            contact: vec![
+                Contact {
+                    address: "1-555-234523".to_owned(),
+                    kind: ContactType::Phone,
+                    tag: Some(Tag("work".to_owned())),
+                }
+            ],
+            gender: Gender::Female,
+            age: 42,
+            active: true,
+            groups: vec![],
+        };
+        let header = core.run(conn.execute(InsertDocument::new(
+            "customers135", NewDocument::from_content(customer.clone())
+        ))).unwrap();
+        let (document_id, _, revision) = header.deconstruct();
+
+        let method = GetDocument::<Customer>::new(document_id)
+            .with_if_none_match(revision);
+        let document = core.run(conn.execute(method)).unwrap();
+
+        assert_eq!(None, document);
+    });
+}
+
 #[test]
 fn get_document_if_revision_is_not_a_match() {
     arango_test_with_document_collection("customers14", |conn, ref mut core| {
@@ -660,7 +696,8 @@ fn get_document_if_revision_is_not_a_match() {
 
         let method = GetDocument::new(document_id.clone())
             .with_if_non_match(String::from("not") + revision.as_str());
-        let document = core.run(conn.execute(method)).unwrap();
+        let document = core.run(conn.execute(method)).unwrap()
+            .expect("document should not have been modified");
 
         assert_eq!("customers14", document.id().collection_name());
         assert_eq!(&document_id, document.id());
@@ -695,13 +732,18 @@ fn get_document_but_revision_does_not_match() {
 
         let method = GetDocument::<Customer>::new(document_id)
             .with_if_match(String::from("not") + revision.as_str());
-        let result = core.run(conn.execute(method));
+        let result = core.run(conn.execute(method.clone()));
 
         match result {
-            Err(Error::ApiError(error)) => {
+            Err(Error::ApiError(ref error)) => {
                 assert_eq!(412, error.status_code());
                 assert_eq!(ErrorCode::ArangoConflict, error.error_code());
                 assert_eq!("precondition failed", error.message());
+
+                match method.classify_error(error) {
+                    DocumentError::RevisionConflict { .. } => (),
+                    other => panic!("Expected a revision conflict, but got: {:?}", other),
+                }
             },
             _ => panic!("Error expected, but got: {:?}", &result),
         }
@@ -757,9 +799,8 @@ fn get_document_for_id_that_does_not_exist() {
     });
 }
 
-#[ignore] //TODO refactor get document header to document exists (with possibly returning the revision)
 #[test]
-fn get_document_header() {
+fn document_exists() {
     arango_test_with_document_collection("customers20", |conn, ref mut core| {
 
         let customer = Customer {
@@ -781,10 +822,10 @@ fn get_document_header() {
                 .with_key(DocumentKey::new("7721264"))
         ))).unwrap();
 
-        let method = GetDocumentHeader::new(inserted.id().clone());
+        let method = DocumentExists::new(inserted.id().clone());
         let result = core.run(conn.execute(method)).unwrap();
 
-        assert_eq!((), result);
+        assert_eq!(Some(inserted.revision().clone()), result);
     });
 }
 
@@ -1442,11 +1483,13 @@ fn insert_two_struct_documents_with_same_key() {
             panic!("Expected document header 1, but got: {:?}", documents.get(0))
         }
 
-        if let Err(ref error) = documents.get(1).unwrap() {
-            assert_eq!(ErrorCode::ArangoUniqueConstraintViolated, error.code());
-            assert_eq!("unique constraint violated - in index 0 of type primary over [\"_key\"]", error.message());
-        } else {
-            panic!("Expected method error, but got: {:?}", documents.get(1))
+        match documents.get(1).unwrap() {
+            &Err(MethodError::UniqueConstraintViolated { ref index, ref index_type, ref fields }) => {
+                assert_eq!(Some("0".to_owned()), *index);
+                assert_eq!(Some("primary".to_owned()), *index_type);
+                assert_eq!(vec!["_key".to_owned()], *fields);
+            },
+            other => panic!("Expected a unique constraint violation, but got: {:?}", other),
         }
     });
 }
@@ -1503,11 +1546,13 @@ fn insert_two_struct_documents_with_same_key_and_return_new() {
             panic!("Expected document 1, but got: {:?}", documents.get(0));
         }
 
-        if let Err(ref error) = documents.get(1).unwrap() {
-            assert_eq!(ErrorCode::ArangoUniqueConstraintViolated, error.code());
-            assert_eq!("unique constraint violated - in index 0 of type primary over [\"_key\"]", error.message());
-        } else {
-            panic!("Expected method error, but got: {:?}", documents.get(1))
+        match documents.get(1).unwrap() {
+            &Err(MethodError::UniqueConstraintViolated { ref index, ref index_type, ref fields }) => {
+                assert_eq!(Some("0".to_owned()), *index);
+                assert_eq!(Some("primary".to_owned()), *index_type);
+                assert_eq!(vec!["_key".to_owned()], *fields);
+            },
+            other => panic!("Expected a unique constraint violation, but got: {:?}", other),
         }
     });
 }